@@ -0,0 +1,95 @@
+//  EVENT BUS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:18:09
+//  Last edited:
+//    17 Oct 2026, 09:18:09
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for collections with the
+//!   `collection_impls`-macro.
+//
+
+use std::collections::VecDeque;
+
+use auto_traits::{collection_impls, pointer_impls};
+
+
+/***** TRAITS *****/
+// Every call on a collection fans out to its elements, and their results are combined per method.
+// Together with `#[pointer_impls]`, this also covers collections of trait objects.
+#[collection_impls]
+#[pointer_impls]
+trait Listener {
+    // Associated types are taken from the elements.
+    type Event;
+
+    // Methods returning nothing are called in order.
+    fn on_event(&mut self, event: &Self::Event);
+    #[collection_impl(reduce = and)]
+    fn is_ready(&self) -> bool;
+    #[collection_impl(reduce = or)]
+    fn has_seen(&self, event: &Self::Event) -> bool;
+    #[collection_impl(reduce = sum)]
+    fn seen(&self) -> usize;
+    #[collection_impl(reduce = collect)]
+    fn history(&self) -> Vec<String>;
+}
+
+
+// Let's implement it for some objects.
+struct Logger(Vec<String>);
+impl Listener for Logger {
+    type Event = &'static str;
+
+    fn on_event(&mut self, event: &Self::Event) { self.0.push((*event).into()); }
+    fn is_ready(&self) -> bool { true }
+    fn has_seen(&self, event: &Self::Event) -> bool { self.0.iter().any(|e| e == event) }
+    fn seen(&self) -> usize { self.0.len() }
+    fn history(&self) -> Vec<String> { self.0.clone() }
+}
+
+struct Counter(usize);
+impl Listener for Counter {
+    type Event = &'static str;
+
+    fn on_event(&mut self, _event: &Self::Event) { self.0 += 1; }
+    fn is_ready(&self) -> bool { self.0 < 10 }
+    fn has_seen(&self, _event: &Self::Event) -> bool { false }
+    fn seen(&self) -> usize { self.0 }
+    fn history(&self) -> Vec<String> { vec![format!("{} events", self.0)] }
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn publish<L: Listener<Event = &'static str>>(listener: &mut L) { listener.on_event(&"hello") }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let mut bus: Vec<Box<dyn Listener<Event = &'static str>>> = vec![Box::new(Logger(vec![])), Box::new(Counter(0))];
+    publish(&mut bus);
+    publish(&mut bus);
+    assert!(bus.is_ready());
+    assert!(bus.has_seen(&"hello"));
+    assert!(!bus.has_seen(&"world"));
+    assert_eq!(bus.seen(), 4);
+    assert_eq!(bus.history(), ["hello", "hello", "2 events"]);
+
+    // Other collections work too, including empty ones
+    let mut counters: VecDeque<Counter> = VecDeque::new();
+    publish(&mut counters);
+    assert!(counters.is_ready());
+    assert_eq!(counters.seen(), 0);
+    let mut counters = [Counter(0), Counter(9)];
+    publish(&mut counters);
+    assert!(!counters.is_ready());
+    assert_eq!(counters[..1].seen(), 1);
+}
@@ -0,0 +1,73 @@
+//  SINKS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:18:09
+//  Last edited:
+//    17 Oct 2026, 09:18:09
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement generic traits for collections with the
+//!   `collection_impls`-macro.
+//
+
+use std::collections::VecDeque;
+
+use auto_traits::collection_impls;
+
+
+/***** TRAITS *****/
+// The trait's own generics are kept, and can be named anything (including `T` and `N`, which
+// the element type and array length are renamed around).
+#[collection_impls]
+trait Sink<T: Clone, const N: usize> {
+    // Arguments taken by value are cloned for every element
+    fn send(&mut self, item: T);
+    fn send_all(&mut self, items: &[T; N]);
+    #[collection_impl(reduce = sum)]
+    fn pending(&self) -> usize;
+}
+
+// A sink that buffers items, and one that only counts them
+struct Buffer<T>(Vec<T>);
+impl<T: Clone, const N: usize> Sink<T, N> for Buffer<T> {
+    fn send(&mut self, item: T) { self.0.push(item); }
+    fn send_all(&mut self, items: &[T; N]) { self.0.extend_from_slice(items); }
+    fn pending(&self) -> usize { self.0.len() }
+}
+struct Counter(usize);
+impl<T: Clone, const N: usize> Sink<T, N> for Counter {
+    fn send(&mut self, _item: T) { self.0 += 1; }
+    fn send_all(&mut self, _items: &[T; N]) { self.0 += N; }
+    fn pending(&self) -> usize { self.0 }
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn flush<T: Clone>(sink: &mut impl Sink<T, 2>, items: [T; 3]) -> usize {
+    let [a, b, c] = items;
+    sink.send(a);
+    sink.send_all(&[b, c]);
+    sink.pending()
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Every element gets a copy of the items...
+    let mut buffers: Vec<Buffer<String>> = vec![Buffer(Vec::new()), Buffer(Vec::new())];
+    assert_eq!(flush(&mut buffers, ["a".into(), "b".into(), "c".into()]), 6);
+    assert_eq!(buffers[1].0, ["a", "b", "c"]);
+
+    // ...for any of the collections
+    let mut counters: VecDeque<Counter> = VecDeque::from([Counter(0), Counter(10)]);
+    assert_eq!(flush(&mut counters, [1, 2, 3]), 16);
+    let mut counters: [Counter; 3] = [Counter(0), Counter(0), Counter(0)];
+    assert_eq!(flush(&mut counters, [1u8, 2, 3]), 9);
+}
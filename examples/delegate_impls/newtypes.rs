@@ -0,0 +1,94 @@
+//  NEWTYPES.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 08:04:06
+//  Last edited:
+//    17 Oct 2026, 08:04:06
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for newtypes with the `delegate_impls`-
+//!   and `delegate`-macros.
+//
+
+use auto_traits::delegate;
+
+
+/***** TRAITS *****/
+mod units {
+    use auto_traits::{delegate_impls, pointer_impls};
+
+    // The trait needs to describe itself for `#[delegate]` to see its items. This combines with
+    // `#[pointer_impls]`, too.
+    #[delegate_impls]
+    #[pointer_impls]
+    pub trait Length {
+        type Unit;
+        const DIMENSIONS: usize;
+
+        fn length(&self) -> f64;
+        fn scale(&mut self, factor: f64);
+        fn into_length(self) -> f64
+        where
+            Self: Sized;
+        fn unit(&self) -> Self::Unit;
+        fn describe(&self) -> String { format!("{}", self.length()) }
+    }
+}
+
+
+// Let's implement it for some object.
+struct Line(f64);
+impl units::Length for Line {
+    type Unit = &'static str;
+    const DIMENSIONS: usize = 1;
+
+    fn length(&self) -> f64 { self.0 }
+    fn scale(&mut self, factor: f64) { self.0 *= factor; }
+    fn into_length(self) -> f64 { self.0 }
+    fn unit(&self) -> Self::Unit { "m" }
+}
+
+
+
+// Newtypes with one field delegate to it...
+#[delegate(units::Length)]
+struct Meters(Line);
+
+// ...and others need to say which.
+#[delegate(units::Length, to = self.line)]
+struct Labeled<'a, T> {
+    label: &'a str,
+    line:  T,
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn length(length: &impl units::Length) -> f64 { length.length() }
+fn scale(length: &mut impl units::Length) { length.scale(2.0) }
+fn unit<L: units::Length>(length: &L) -> L::Unit { length.unit() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    use units::Length as _;
+
+    let mut meters = Meters(Line(21.0));
+    scale(&mut meters);
+    assert_eq!(length(&meters), 42.0);
+    assert_eq!(meters.describe(), "42");
+    assert_eq!(unit(&meters), "m");
+    assert_eq!(<Meters as units::Length>::DIMENSIONS, 1);
+    assert_eq!(meters.into_length(), 42.0);
+
+    let labeled = Labeled { label: "height", line: Meters(Line(2.0)) };
+    assert_eq!(labeled.label, "height");
+    assert_eq!(unit(&labeled), "m");
+    assert_eq!(length(&Box::new(labeled)), 2.0);
+}
@@ -0,0 +1,118 @@
+//  SHAPES.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 08:08:57
+//  Last edited:
+//    17 Oct 2026, 08:08:57
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for enums with the `dispatch_impls`-macro.
+//
+
+use auto_traits::{delegate_impls, dispatch_impls};
+
+
+/***** TRAITS *****/
+// The trait needs to describe itself for `#[dispatch_impls]` to see its items.
+#[delegate_impls]
+trait Shape {
+    type Unit;
+    const CORNERS: usize;
+
+    fn area(&self) -> f64;
+    fn grow(&mut self, factor: f64);
+    fn unit(&self) -> Self::Unit;
+    fn doubled(&self) -> Self
+    where
+        Self: Sized;
+    fn unit_sized() -> Self
+    where
+        Self: Sized;
+    // Methods with default implementations are inherited, unless forwarded like with
+    // `#[pointer_impls]`
+    #[pointer_impl(forward)]
+    fn name(&self) -> String { "shape".into() }
+}
+
+
+// Let's implement it for some objects.
+#[derive(Debug, PartialEq)]
+struct Square(f64);
+impl Shape for Square {
+    type Unit = &'static str;
+    const CORNERS: usize = 4;
+
+    fn area(&self) -> f64 { self.0 * self.0 }
+    fn grow(&mut self, factor: f64) { self.0 *= factor; }
+    fn unit(&self) -> Self::Unit { "m" }
+    fn doubled(&self) -> Self { Self(2.0 * self.0) }
+    fn unit_sized() -> Self { Self(1.0) }
+    fn name(&self) -> String { "square".into() }
+}
+
+#[derive(Debug, PartialEq)]
+struct Rectangle {
+    width:  f64,
+    height: f64,
+}
+impl Shape for Rectangle {
+    type Unit = &'static str;
+    const CORNERS: usize = 4;
+
+    fn area(&self) -> f64 { self.width * self.height }
+    fn grow(&mut self, factor: f64) {
+        self.width *= factor;
+        self.height *= factor;
+    }
+    fn unit(&self) -> Self::Unit { "m" }
+    fn doubled(&self) -> Self { Self { width: 2.0 * self.width, height: 2.0 * self.height } }
+    fn unit_sized() -> Self { Self { width: 1.0, height: 1.0 } }
+}
+
+
+
+// The associated type is the same for every variant, but the constant and the methods returning
+// `Self` need to be told what to do.
+#[dispatch_impls(Shape, const CORNERS = 4, fn doubled = wrap, fn unit_sized = Square)]
+#[derive(Debug, PartialEq)]
+enum AnyShape {
+    Square(Square),
+    Rectangle { rect: Rectangle },
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn area(shape: &impl Shape) -> f64 { shape.area() }
+fn grow(shape: &mut impl Shape) { shape.grow(2.0) }
+fn unit<S: Shape>(shape: &S) -> S::Unit { shape.unit() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let mut square = AnyShape::Square(Square(2.0));
+    let mut rect = AnyShape::Rectangle { rect: Rectangle { width: 2.0, height: 3.0 } };
+    assert_eq!(area(&square), 4.0);
+    assert_eq!(area(&rect), 6.0);
+    grow(&mut square);
+    grow(&mut rect);
+    assert_eq!(area(&square), 16.0);
+    assert_eq!(area(&rect), 24.0);
+    assert_eq!(unit(&rect), "m");
+    assert_eq!(<AnyShape as Shape>::CORNERS, 4);
+
+    // The forwarded default method is dispatched to the variant's too
+    assert_eq!(square.name(), "square");
+    assert_eq!(rect.name(), "shape");
+
+    // And these use the strategies
+    assert_eq!(square.doubled(), AnyShape::Square(Square(8.0)));
+    assert_eq!(rect.doubled(), AnyShape::Rectangle { rect: Rectangle { width: 8.0, height: 12.0 } });
+    assert_eq!(AnyShape::unit_sized(), AnyShape::Square(Square(1.0)));
+}
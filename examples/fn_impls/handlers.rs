@@ -0,0 +1,85 @@
+//  HANDLERS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:20:32
+//  Last edited:
+//    17 Oct 2026, 09:20:32
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for closures with the `fn_impls`-macro.
+//
+
+use auto_traits::fn_impls;
+
+
+/***** TRAITS *****/
+struct Request {
+    path: String,
+}
+struct Response {
+    status: u16,
+}
+
+// Methods taking `&self` are implemented for `Fn`-closures...
+#[fn_impls]
+trait Handler {
+    fn handle(&self, req: &Request) -> Response;
+    // Methods with a default implementation are inherited
+    fn is_ok(&self, req: &Request) -> bool { self.handle(req).status == 200 }
+}
+
+// ...those taking `&mut self` for `FnMut`-closures...
+#[fn_impls]
+trait Middleware {
+    fn process(&mut self, req: Request) -> Request;
+}
+
+// ...and those taking `self` for `FnOnce`-closures.
+#[fn_impls]
+trait Shutdown {
+    fn shutdown(self, reason: &str) -> String
+    where
+        Self: Sized;
+}
+
+// Lifetimes of the method are given to the closure with a higher-ranked bound.
+#[fn_impls]
+trait Router {
+    fn route<'r>(&self, req: &'r Request) -> &'r str;
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn serve(handler: &impl Handler, path: &str) -> u16 { handler.handle(&Request { path: path.into() }).status }
+fn route(router: &impl Router, req: &Request) -> String { router.route(req).into() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let handler = |req: &Request| Response { status: if req.path == "/" { 200 } else { 404 } };
+    assert_eq!(serve(&handler, "/"), 200);
+    assert_eq!(serve(&handler, "/foo"), 404);
+    assert!(handler.is_ok(&Request { path: "/".into() }));
+
+    let mut seen: usize = 0;
+    let mut middleware = |req: Request| {
+        seen += 1;
+        Request { path: format!("/api{}", req.path) }
+    };
+    assert_eq!(middleware.process(Request { path: "/".into() }).path, "/api/");
+    assert_eq!(seen, 1);
+
+    let server = String::from("server");
+    let shutdown = move |reason: &str| format!("{server} stopped: {reason}");
+    assert_eq!(shutdown.shutdown("done"), "server stopped: done");
+
+    fn first_segment(req: &Request) -> &str { req.path.split('/').nth(1).unwrap_or("") }
+    assert_eq!(route(&first_segment, &Request { path: "/api/users".into() }), "api");
+}
@@ -0,0 +1,78 @@
+//  ADAPTERS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:25:17
+//  Last edited:
+//    17 Oct 2026, 10:29:21
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits with closures with the `fns_impls`-macro.
+//
+
+use std::collections::HashMap;
+
+use auto_traits::fns_impls;
+
+
+/***** TRAITS *****/
+// Generates a `StorageFns` struct that calls a closure for every method.
+#[fns_impls]
+trait Storage {
+    fn get(&self, key: &str) -> Option<String>;
+    fn set(&mut self, key: &str, value: String);
+    // Methods with default implementations keep them, unless given a closure too.
+    fn contains(&self, key: &str) -> bool { self.get(key).is_some() }
+    // Methods taking `self` are given an `FnOnce`.
+    fn close(self) -> usize
+    where
+        Self: Sized;
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn cache(storage: &mut impl Storage, key: &str) -> String {
+    if let Some(value) = storage.get(key) {
+        return value;
+    }
+    let value: String = key.to_uppercase();
+    storage.set(key, value.clone());
+    value
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // A quick adapter around a map...
+    let map: std::cell::RefCell<HashMap<String, String>> = Default::default();
+    let mut storage = Storage::from_fns()
+        .get(|key| map.borrow().get(key).cloned())
+        .set(|key, value| {
+            map.borrow_mut().insert(key.into(), value);
+        })
+        .close(|| map.borrow().len())
+        .build();
+    assert!(!storage.contains("foo"));
+    assert_eq!(cache(&mut storage, "foo"), "FOO");
+    assert!(storage.contains("foo"));
+    assert_eq!(storage.close(), 1);
+
+    // ...or a stub in a test
+    let mut sets: Vec<String> = Vec::new();
+    let mut stub = Storage::from_fns()
+        .get(|_| None)
+        .set(|key, _| sets.push(key.into()))
+        .contains(|_| true)
+        .close(|| 0)
+        .build();
+    assert!(stub.contains("bar"));
+    assert_eq!(cache(&mut stub, "bar"), "BAR");
+    assert_eq!(stub.close(), 0);
+    assert_eq!(sets, ["bar"]);
+}
@@ -0,0 +1,52 @@
+//  BUILDERS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 10:31:07
+//  Last edited:
+//    17 Oct 2026, 10:31:07
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `fns_impls`-macro works for traits with builder-like method names.
+//
+
+use auto_traits::fns_impls;
+
+
+/***** TRAITS *****/
+// The setters of the generated `RequestFns` are named after the methods, so they're all fine here.
+// Only `build()` and `from_fns()` are taken by the builder itself; those methods are rejected.
+#[fns_impls]
+trait Request {
+    fn builder(&self) -> String;
+    fn with_header(&mut self, name: &str, value: &str);
+    fn r#finish(self) -> usize
+    where
+        Self: Sized;
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn send(mut request: impl Request) -> usize {
+    request.with_header("Host", &request.builder());
+    request.finish()
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let request = Request::from_fns()
+        .builder(|| "example.com".into())
+        .with_header(|name, value| headers.push((name.into(), value.into())))
+        .finish(|| 1)
+        .build();
+    assert_eq!(send(request), 1);
+    assert_eq!(headers, [("Host".to_string(), "example.com".to_string())]);
+}
@@ -0,0 +1,83 @@
+//  SERVICE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:36:10
+//  Last edited:
+//    17 Oct 2026, 09:36:10
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to test code against a mocked trait with the `mock_impls`-macro.
+//
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use auto_traits::{mock_impls, pointer_impls};
+
+
+/***** TRAITS *****/
+// Generates a `MockMailer` and a `MailerCall`-enum with a variant for every method.
+#[mock_impls(derive(Debug, PartialEq))]
+#[pointer_impls]
+trait Mailer<'m> {
+    // Associated types become type parameters of the mock
+    type Error;
+
+    // References are recorded by cloning what they point to (e.g., `&str` as a `String`)...
+    fn send(&self, to: &'m str, body: &[u8]) -> Result<usize, Self::Error>;
+    // ...and anything that can't be stored is formatted with `Debug` instead.
+    fn log<D: std::fmt::Debug>(&self, what: D);
+    // Methods with a default implementation are inherited, unless asked otherwise
+    #[mock_impl(mock)]
+    #[pointer_impl(forward)]
+    fn is_online(&self) -> bool { true }
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn notify<'m>(mailer: impl Mailer<'m, Error = String>, users: &[&'m str]) -> usize {
+    if !mailer.is_online() {
+        return 0;
+    }
+    let mut sent: usize = 0;
+    for user in users {
+        match mailer.send(user, b"hello") {
+            Ok(n) => sent += n,
+            Err(err) => mailer.log(err),
+        }
+    }
+    sent
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Queue what the mailer should return...
+    let mock: Arc<MockMailer<String>> = Arc::new(MockMailer::new());
+    mock.expect_is_online().returning(true);
+    mock.expect_send().returning(Ok(5)).returning(Err("bounced".into()));
+
+    // ...run the code under test with it, through a pointer...
+    assert_eq!(notify(mock.clone(), &["amy", "bob"]), 5);
+
+    // ...and check how it was called.
+    assert_eq!(*mock.calls(), [
+        MailerCall::IsOnline,
+        MailerCall::Send { to: "amy".into(), body: b"hello".to_vec() },
+        MailerCall::Send { to: "bob".into(), body: b"hello".to_vec() },
+        MailerCall::Log { what: "\"bounced\"".into() },
+    ]);
+
+    // The same works for any of the pointers implemented by `pointer_impls`
+    let mock: Rc<MockMailer<String>> = Rc::new(MockMailer::default());
+    mock.expect_is_online().returning(false);
+    assert_eq!(notify(&*mock, &["amy"]), 0);
+    assert_eq!(mock.calls().len(), 1);
+}
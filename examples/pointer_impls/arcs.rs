@@ -0,0 +1,85 @@
+//  ARCS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:39:34
+//  Last edited:
+//    17 Oct 2026, 07:39:34
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for alternative `Arc` implementations
+//!   with the `pointer_impls`-macro.
+//
+
+use archery::{ArcK, RcK, SharedPointer};
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Every crate has its own group.
+#[pointer_impls(impl triomphe, impl servo_arc, impl archery)]
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+// Mutable access is only given by the unique pointers.
+#[pointer_impls(unimpl *, impl triomphe, impl servo_arc, impl archery)]
+trait Rename {
+    fn rename(&mut self, name: &str);
+}
+
+// And `triomphe` also supports moving out of its pointers.
+#[pointer_impls(unimpl *, impl triomphe, owned = unwrap)]
+trait IntoName {
+    fn into_name(self) -> String;
+}
+
+
+// Let's implement it for some object.
+struct Foo(String);
+impl Describe for Foo {
+    fn describe(&self) -> String { format!("Foo({})", self.0) }
+}
+impl Rename for Foo {
+    fn rename(&mut self, name: &str) { self.0 = name.into(); }
+}
+impl IntoName for Foo {
+    fn into_name(self) -> String { self.0 }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn describe(describe: impl Describe) -> String { describe.describe() }
+fn rename(rename: &mut impl Rename) { rename.rename("Bar") }
+fn into_name(name: impl IntoName) -> String { name.into_name() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // `triomphe`
+    let arc = triomphe::Arc::new(Foo("Foo".into()));
+    assert_eq!(describe(arc.clone()), "Foo(Foo)");
+    assert_eq!(describe(arc.borrow_arc()), "Foo(Foo)");
+    assert_eq!(into_name(arc), "Foo");
+    let mut unique = triomphe::UniqueArc::new(Foo("Foo".into()));
+    rename(&mut unique);
+    assert_eq!(into_name(unique), "Bar");
+
+    // `servo_arc`
+    let arc = servo_arc::Arc::new(Foo("Foo".into()));
+    assert_eq!(describe(arc.clone()), "Foo(Foo)");
+    assert_eq!(describe(arc.borrow_arc()), "Foo(Foo)");
+    let mut unique = servo_arc::UniqueArc::new(Foo("Foo".into()));
+    rename(&mut unique);
+    assert_eq!(unique.0, "Bar");
+
+    // `archery`, for any kind of pointer
+    assert_eq!(describe(SharedPointer::<_, ArcK>::new(Foo("Foo".into()))), "Foo(Foo)");
+    assert_eq!(describe(SharedPointer::<_, RcK>::new(Foo("Foo".into()))), "Foo(Foo)");
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    16 Dec 2024, 14:27:33
 //  Last edited:
-//    15 Jan 2025, 10:43:56
+//    17 Oct 2026, 07:07:19
 //  Auto updated?
 //    Yes
 //
@@ -45,12 +45,12 @@ impl DisplayFoo for Foo {}
 
 
 // This method is just to showcase for what `HelloWorld` is implemented
-fn hello_world<F>(foo: F)
+fn hello_world<F>(helloer: F)
 where
     F: DisplayFoo,
     F::Foo: Display,
 {
-    println!("{}", foo.foo());
+    println!("{}", helloer.foo());
 }
 
 
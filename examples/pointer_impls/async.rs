@@ -0,0 +1,118 @@
+//  ASYNC.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:18:06
+//  Last edited:
+//    17 Oct 2026, 07:18:06
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `pointer_impls`-macro also works for `async` methods
+//!   and methods returning `impl Trait`.
+//
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::task::{Context, Poll, Waker};
+
+use auto_traits::pointer_impls;
+
+
+/***** HELPERS *****/
+/// Runs a future to completion on the current thread.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(res) = fut.as_mut().poll(&mut cx) {
+            return res;
+        }
+    }
+}
+
+/// Asserts a future is [`Send`].
+fn assert_send<F: Future + Send>(fut: F) -> F { fut }
+
+
+
+
+
+/***** TRAITS *****/
+// Define some trait - with pointer implementations!
+#[pointer_impls]
+trait Fetch {
+    async fn fetch(&self, id: u32) -> String;
+
+    fn ids(&self) -> impl Iterator<Item = u32> + '_;
+}
+
+// Also one for mutable pointers
+#[pointer_impls]
+trait Store {
+    async fn store(&mut self, id: u32);
+}
+
+
+// Let's implement it for some object.
+#[derive(Default)]
+struct Foo(Vec<u32>);
+impl Fetch for Foo {
+    async fn fetch(&self, id: u32) -> String { format!("Fetched {id}") }
+
+    fn ids(&self) -> impl Iterator<Item = u32> + '_ { self.0.iter().copied() }
+}
+impl Store for Foo {
+    async fn store(&mut self, id: u32) { self.0.push(id); }
+}
+
+
+
+// These methods are just to showcase for what `Fetch` and `Store` are implemented
+fn fetch(fetcher: impl Fetch) -> (String, Vec<u32>) { (block_on(fetcher.fetch(42)), fetcher.ids().collect()) }
+fn store(mut store: impl Store) { block_on(store.store(42)) }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Async methods and methods returning `impl Trait` are forwarded for all pointers
+    let value = Foo(vec![1, 2, 3]);
+    let expected = ("Fetched 42".to_string(), vec![1, 2, 3]);
+    assert_eq!(fetch(&value), expected);
+    let mut value = Foo(vec![1, 2, 3]);
+    assert_eq!(fetch(&mut value), expected);
+    assert_eq!(fetch(Box::new(Foo(vec![1, 2, 3]))), expected);
+    assert_eq!(fetch(Rc::new(Foo(vec![1, 2, 3]))), expected);
+    assert_eq!(fetch(Arc::new(Foo(vec![1, 2, 3]))), expected);
+    let cell = RefCell::new(Foo(vec![1, 2, 3]));
+    assert_eq!(fetch(cell.borrow()), expected);
+    assert_eq!(fetch(cell.borrow_mut()), expected);
+    let mutex = Mutex::new(Foo(vec![1, 2, 3]));
+    assert_eq!(fetch(mutex.lock().unwrap()), expected);
+    let rwlock = RwLock::new(Foo(vec![1, 2, 3]));
+    assert_eq!(fetch(rwlock.read().unwrap()), expected);
+    assert_eq!(fetch(rwlock.write().unwrap()), expected);
+
+    // Including the mutable ones
+    let mut value = Foo::default();
+    store(&mut value);
+    store(Box::new(&mut value));
+    store(cell.borrow_mut());
+    store(mutex.lock().unwrap());
+    store(rwlock.write().unwrap());
+    assert_eq!(value.0, [42, 42]);
+    assert_eq!(cell.borrow().0, [1, 2, 3, 42]);
+    assert_eq!(mutex.lock().unwrap().0, [1, 2, 3, 42]);
+    assert_eq!(rwlock.read().unwrap().0, [1, 2, 3, 42]);
+
+    // The futures are `Send` if the inner ones are
+    let shared = Arc::new(Foo::default());
+    assert_eq!(block_on(assert_send(shared.fetch(42))), "Fetched 42");
+}
@@ -0,0 +1,65 @@
+//  BOUNDS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:19:53
+//  Last edited:
+//    17 Oct 2026, 07:19:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to add additional bounds to the impls generated by the
+//!   `pointer_impls`-macro.
+//
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::thread;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Define some trait - with pointer implementations for `Cow`s! These only exist if `_: ToOwned`.
+#[pointer_impls(impl<'a> Cow<'a, _> where _: ToOwned)]
+trait HelloWorld {
+    fn hello_world(&self) -> String;
+}
+
+// This one is only implemented for thread-safe types, which is given for all impls.
+#[pointer_impls(where T: Send + Sync + 'static)]
+trait Spawn {
+    fn spawn_hello(self: Arc<Self>) -> thread::JoinHandle<String>;
+}
+
+
+// Let's implement it for some object.
+#[derive(Clone)]
+struct Foo;
+impl HelloWorld for Foo {
+    fn hello_world(&self) -> String { "Hello, world!".into() }
+}
+impl Spawn for Foo {
+    fn spawn_hello(self: Arc<Self>) -> thread::JoinHandle<String> { thread::spawn(move || self.hello_world()) }
+}
+
+
+
+// These methods are just to showcase for what `HelloWorld` and `Spawn` are implemented
+fn hello_world(helloer: impl HelloWorld) -> String { helloer.hello_world() }
+fn spawn_hello<S: Spawn + ?Sized>(spawner: Arc<S>) -> String { spawner.spawn_hello().join().unwrap() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // The `Cow` works, both borrowed and owned
+    assert_eq!(hello_world(Cow::Borrowed(&Foo)), "Hello, world!");
+    assert_eq!(hello_world(Cow::<Foo>::Owned(Foo)), "Hello, world!");
+
+    // And `Arc` is also implemented for thread-safe types
+    assert_eq!(spawn_hello(Arc::new(Arc::new(Foo))), "Hello, world!");
+}
@@ -0,0 +1,69 @@
+//  CONSTANTS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:16:42
+//  Last edited:
+//    17 Oct 2026, 07:16:42
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `pointer_impls`-macro also works for associated
+//!   constants.
+//
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Define some trait - with pointer implementations!
+#[pointer_impls]
+trait Config {
+    type Level;
+
+    /// This one is simply forwarded...
+    const NAME: &'static str;
+    /// ...as is this one, even though it has a default...
+    const RETRIES: usize = 3;
+    /// ...and this one, because `Self::Level` is forwarded too.
+    const LEVEL: Self::Level;
+    /// But this one is given its own value for some pointers.
+    #[pointer_impl(value(::std::rc::Rc<_>, ::std::sync::Arc<_>) = true)]
+    const IS_SHARED: bool = false;
+}
+
+
+// Let's implement it for some object.
+struct Foo;
+impl Config for Foo {
+    type Level = u8;
+
+    const NAME: &'static str = "Foo";
+    const RETRIES: usize = 5;
+    const LEVEL: Self::Level = 42;
+}
+
+
+
+// This method is just to showcase for what `Config` is implemented
+fn config<C: Config>(_config: C) -> (&'static str, usize, C::Level, bool) { (C::NAME, C::RETRIES, C::LEVEL, C::IS_SHARED) }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // The constants are the same for all pointers...
+    assert_eq!(config(Foo), ("Foo", 5, 42, false));
+    assert_eq!(config(&Foo), ("Foo", 5, 42, false));
+    assert_eq!(config(Box::new(Foo)), ("Foo", 5, 42, false));
+
+    // ...except when overridden
+    assert_eq!(config(Rc::new(Foo)), ("Foo", 5, 42, true));
+    assert_eq!(config(Arc::new(Foo)), ("Foo", 5, 42, true));
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    16 Dec 2024, 12:02:31
 //  Last edited:
-//    16 Dec 2024, 14:27:10
+//    17 Oct 2026, 07:21:13
 //  Auto updated?
 //    Yes
 //
@@ -44,11 +44,25 @@ trait HelloWorld {
     fn hello_world(&self) -> &str;
 }
 
+// If the trait needs different kinds of access, we can give an expression for every one of them.
+#[pointer_impls(unimpl *, impl Pointer2<_> { ref = &self.0, mut = &mut self.0, owned = self.0 })]
+trait Greeter {
+    fn greeting(&self) -> &str;
+    fn set_greeting(&mut self, greeting: &'static str);
+    fn into_greeting(self) -> String;
+}
+
 // Let's implement it for some object.
 struct Foo;
 impl HelloWorld for Foo {
     fn hello_world(&self) -> &str { "Hello, world!" }
 }
+struct Bar(&'static str);
+impl Greeter for Bar {
+    fn greeting(&self) -> &str { self.0 }
+    fn set_greeting(&mut self, greeting: &'static str) { self.0 = greeting; }
+    fn into_greeting(self) -> String { self.0.into() }
+}
 
 
 
@@ -69,4 +83,10 @@ fn main() {
     // However, this isn't possible without the `#[pointer_impls]`! (try it!)
     hello_world(Pointer1(Foo));
     hello_world(Pointer2(Foo));
+
+    // Every method uses the right expression
+    let mut greeter = Pointer2(Bar("Hello, world!"));
+    assert_eq!(greeter.greeting(), "Hello, world!");
+    greeter.set_greeting("Goodbye, world!");
+    assert_eq!(greeter.into_greeting(), "Goodbye, world!");
 }
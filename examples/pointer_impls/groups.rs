@@ -0,0 +1,78 @@
+//  GROUPS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:22:56
+//  Last edited:
+//    17 Oct 2026, 07:24:23
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to (un)implement groups of types at once with the
+//!   `pointer_impls`-macro, and how to refer to single types.
+//
+
+use std::rc::Rc;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Only implement for references and smart pointers
+#[pointer_impls(unimpl *, impl refs, impl smart)]
+trait HelloWorld {
+    fn hello_world(&self) -> &str;
+}
+
+// Implement for everything except guards
+#[pointer_impls(unimpl cells, unimpl std_locks)]
+trait GoodbyeWorld {
+    fn goodbye_world(&self) -> &str;
+}
+
+// Single types can be removed by their usual names too
+#[pointer_impls(unimpl Box<_>, unimpl Ref<_>)]
+trait HelloAgain {
+    fn hello_again(&self) -> &str;
+}
+
+
+// Let's implement it for some object.
+struct Foo;
+impl HelloWorld for Foo {
+    fn hello_world(&self) -> &str { "Hello, world!" }
+}
+impl GoodbyeWorld for Foo {
+    fn goodbye_world(&self) -> &str { "Goodbye, world!" }
+}
+impl HelloAgain for Foo {
+    fn hello_again(&self) -> &str { "Hello again, world!" }
+}
+
+
+
+// These methods are just to showcase for what `HelloWorld` and `GoodbyeWorld` are implemented
+fn hello_world(helloer: impl HelloWorld) -> String { helloer.hello_world().into() }
+fn goodbye_world(goodbyeer: impl GoodbyeWorld) -> String { goodbyeer.goodbye_world().into() }
+fn hello_again(helloer: impl HelloAgain) -> String { helloer.hello_again().into() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // These work...
+    assert_eq!(hello_world(&Foo), "Hello, world!");
+    assert_eq!(hello_world(Box::new(Foo)), "Hello, world!");
+    assert_eq!(hello_world(Rc::new(Foo)), "Hello, world!");
+    assert_eq!(goodbye_world(&Foo), "Goodbye, world!");
+    assert_eq!(goodbye_world(Rc::new(Foo)), "Goodbye, world!");
+    assert_eq!(hello_again(Rc::new(Foo)), "Hello again, world!");
+
+    // ...but these don't (try it!)
+    // hello_world(RefCell::new(Foo).borrow());
+    // goodbye_world(Mutex::new(Foo).lock().unwrap());
+    // hello_again(Box::new(Foo));
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    17 Dec 2024, 15:58:26
 //  Last edited:
-//    17 Dec 2024, 16:41:17
+//    17 Oct 2026, 07:07:19
 //  Auto updated?
 //    Yes
 //
@@ -12,6 +12,9 @@
 //!   TODO
 //
 
+// The lifetimes are explicit on purpose, to showcase they're supported
+#![allow(clippy::needless_lifetimes)]
+
 use auto_traits::pointer_impls;
 
 
@@ -0,0 +1,142 @@
+//  LOCK API.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:36:30
+//  Last edited:
+//    17 Oct 2026, 07:36:30
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for the guards of any `lock_api`-based
+//!   lock with the `pointer_impls`-macro.
+//
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use auto_traits::pointer_impls;
+use lock_api::{GetThreadId, GuardSend, MutexGuard, RawMutex, RawRwLock, RwLockWriteGuard};
+
+
+/***** LOCKS *****/
+/// A (very naive) spinlock, to show that any `lock_api` backend works.
+struct RawSpinLock(AtomicUsize);
+unsafe impl RawMutex for RawSpinLock {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = Self(AtomicUsize::new(0));
+    type GuardMarker = GuardSend;
+
+    fn lock(&self) { while !self.try_lock() {} }
+
+    fn try_lock(&self) -> bool { self.0.compare_exchange(0, 1, Ordering::Acquire, Ordering::Relaxed).is_ok() }
+
+    unsafe fn unlock(&self) { self.0.store(0, Ordering::Release); }
+}
+
+/// A (very naive) read/write spinlock. The counter is the number of readers, or `usize::MAX` if
+/// it's being written.
+struct RawSpinRwLock(AtomicUsize);
+unsafe impl RawRwLock for RawSpinRwLock {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = Self(AtomicUsize::new(0));
+    type GuardMarker = GuardSend;
+
+    fn lock_shared(&self) { while !self.try_lock_shared() {} }
+
+    fn try_lock_shared(&self) -> bool {
+        let readers: usize = self.0.load(Ordering::Relaxed);
+        readers != usize::MAX && self.0.compare_exchange(readers, readers + 1, Ordering::Acquire, Ordering::Relaxed).is_ok()
+    }
+
+    unsafe fn unlock_shared(&self) { self.0.fetch_sub(1, Ordering::Release); }
+
+    fn lock_exclusive(&self) { while !self.try_lock_exclusive() {} }
+
+    fn try_lock_exclusive(&self) -> bool { self.0.compare_exchange(0, usize::MAX, Ordering::Acquire, Ordering::Relaxed).is_ok() }
+
+    unsafe fn unlock_exclusive(&self) { self.0.store(0, Ordering::Release); }
+}
+
+/// Finds the current thread by the address of a thread-local.
+struct ThreadId;
+unsafe impl GetThreadId for ThreadId {
+    const INIT: Self = Self;
+
+    fn nonzero_thread_id(&self) -> NonZeroUsize {
+        thread_local! { static ID: u8 = const { 0 }; }
+        ID.with(|id| NonZeroUsize::new(id as *const u8 as usize).unwrap())
+    }
+}
+
+type Mutex<T> = lock_api::Mutex<RawSpinLock, T>;
+type RwLock<T> = lock_api::RwLock<RawSpinRwLock, T>;
+type ReentrantMutex<T> = lock_api::ReentrantMutex<RawSpinLock, ThreadId, T>;
+
+
+
+
+
+/***** TRAITS *****/
+// One group covers the guards of every `lock_api` backend.
+#[pointer_impls(impl lock_api)]
+trait Counter {
+    fn count(&self) -> u32;
+
+    fn increment(&mut self);
+}
+
+// Reentrant mutexes only give shared access, so these only implement traits without `&mut self`.
+#[pointer_impls(impl lock_api)]
+trait Count {
+    fn count(&self) -> u32;
+}
+
+
+// Let's implement it for some object.
+struct Foo(u32);
+impl Counter for Foo {
+    fn count(&self) -> u32 { self.0 }
+
+    fn increment(&mut self) { self.0 += 1; }
+}
+impl Count for Foo {
+    fn count(&self) -> u32 { self.0 }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn increment(mut counter: impl Counter) -> u32 {
+    counter.increment();
+    counter.count()
+}
+fn count(count: impl Count) -> u32 { count.count() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Plain guards
+    let mutex = Mutex::new(Foo(0));
+    assert_eq!(increment(mutex.lock()), 1);
+    assert_eq!(increment(mutex.lock()), 2);
+    let lock = RwLock::new(Foo(0));
+    assert_eq!(increment(lock.write()), 1);
+    assert_eq!(count(lock.read()), 1);
+
+    // Mapped guards
+    let mutex = Mutex::new((Foo(0), Foo(42)));
+    assert_eq!(increment(MutexGuard::map(mutex.lock(), |(_, second)| second)), 43);
+    let lock = RwLock::new((Foo(0), Foo(42)));
+    assert_eq!(increment(RwLockWriteGuard::map(lock.write(), |(first, _)| first)), 1);
+
+    // Reentrant guards
+    let mutex = ReentrantMutex::new(Foo(42));
+    let guard = mutex.lock();
+    assert_eq!(count(mutex.lock()), 42);
+    assert_eq!(count(guard), 42);
+}
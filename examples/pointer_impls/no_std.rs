@@ -0,0 +1,97 @@
+//  NO STD.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:41:31
+//  Last edited:
+//    17 Oct 2026, 07:41:31
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use the `pointer_impls`-macro in `#![no_std]` crates.
+//!
+//!   Examples always link `std`, so instead, we write the traits in modules
+//!   without a prelude to show that nothing of `std` is used.
+//
+
+extern crate alloc;
+
+
+/***** TRAITS *****/
+/// Without an allocator, only `core`'s types are implemented.
+mod firmware {
+    #![no_implicit_prelude]
+
+    use ::auto_traits::pointer_impls;
+
+    #[pointer_impls(core)]
+    pub trait Sensor {
+        fn read(&self) -> u32;
+
+        fn calibrate(&mut self, offset: u32);
+    }
+}
+
+/// With one, `alloc`'s types are implemented too (given `extern crate alloc;` in the crate root).
+mod driver {
+    #![no_implicit_prelude]
+
+    use ::auto_traits::pointer_impls;
+
+    #[pointer_impls(alloc, impl pin_box)]
+    pub trait Device {
+        fn name(&self) -> &'static str;
+    }
+
+    #[pointer_impls(alloc, owned = unwrap)]
+    pub trait IntoId {
+        fn into_id(self) -> u32;
+    }
+}
+
+use driver::{Device, IntoId};
+use firmware::Sensor;
+
+
+// Let's implement it for some object.
+struct Foo(u32);
+impl Sensor for Foo {
+    fn read(&self) -> u32 { self.0 }
+
+    fn calibrate(&mut self, offset: u32) { self.0 += offset; }
+}
+impl Device for Foo {
+    fn name(&self) -> &'static str { "Foo" }
+}
+impl IntoId for Foo {
+    fn into_id(self) -> u32 { self.0 }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn calibrate(mut sensor: impl Sensor) -> u32 {
+    sensor.calibrate(1);
+    sensor.read()
+}
+fn name(device: impl Device) -> &'static str { device.name() }
+fn into_id(id: impl IntoId) -> u32 { id.into_id() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // `core` only has references and cells
+    assert_eq!(calibrate(&mut Foo(0)), 1);
+    let cell = core::cell::RefCell::new(Foo(0));
+    assert_eq!(calibrate(cell.borrow_mut()), 1);
+
+    // `alloc` also has smart pointers
+    assert_eq!(name(alloc::rc::Rc::new(Foo(42))), "Foo");
+    assert_eq!(name(alloc::boxed::Box::pin(Foo(42))), "Foo");
+    assert_eq!(into_id(alloc::boxed::Box::new(Foo(42))), 42);
+    assert_eq!(into_id(alloc::sync::Arc::new(Foo(42))), 42);
+}
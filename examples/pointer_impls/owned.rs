@@ -0,0 +1,107 @@
+//  OWNED.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:09:53
+//  Last edited:
+//    17 Oct 2026, 07:14:28
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `pointer_impls`-macro also works for methods taking
+//!   `self` by value.
+//
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// By default, this is only implemented for pointers that own their contents (i.e., `Box`).
+#[pointer_impls]
+trait IntoName {
+    fn into_name(self) -> String;
+}
+
+// We can also opt-in to unwrapping shared pointers, which panics if they're still shared...
+#[pointer_impls(owned = unwrap)]
+trait IntoGreeting {
+    fn into_greeting(self) -> String;
+}
+
+// ...or to cloning if they are.
+#[pointer_impls(owned = clone)]
+trait IntoFarewell {
+    fn into_farewell(self) -> String;
+}
+
+// If the method has a default implementation, it's simply inherited for types that can't move.
+// Note that we force `T` to be sized, as the method can otherwise never be forwarded.
+#[pointer_impls(Sized)]
+trait Named {
+    fn name(&self) -> String;
+
+    #[pointer_impl(forward)]
+    fn into_shout(self) -> String
+    where
+        Self: Sized,
+    {
+        self.name().to_uppercase()
+    }
+}
+
+
+// Let's implement it for some object.
+#[derive(Clone)]
+struct Foo;
+impl IntoName for Foo {
+    fn into_name(self) -> String { "Foo".into() }
+}
+impl IntoGreeting for Foo {
+    fn into_greeting(self) -> String { "Hello, Foo!".into() }
+}
+impl IntoFarewell for Foo {
+    fn into_farewell(self) -> String { "Goodbye, Foo!".into() }
+}
+impl Named for Foo {
+    fn name(&self) -> String { "Foo".into() }
+
+    fn into_shout(self) -> String { "FOO!".into() }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn into_name(name: impl IntoName) -> String { name.into_name() }
+fn into_greeting(greeting: impl IntoGreeting) -> String { greeting.into_greeting() }
+fn into_farewell(farewell: impl IntoFarewell) -> String { farewell.into_farewell() }
+fn into_shout(named: impl Named) -> String { named.into_shout() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Only boxes can give up their contents by default
+    assert_eq!(into_name(Foo), "Foo");
+    assert_eq!(into_name(Box::new(Foo)), "Foo");
+
+    // Unless we tell the macro to do it anyway...
+    assert_eq!(into_greeting(Box::new(Foo)), "Hello, Foo!");
+    assert_eq!(into_greeting(Rc::new(Foo)), "Hello, Foo!");
+    assert_eq!(into_greeting(Arc::new(Foo)), "Hello, Foo!");
+
+    // ...or clone if necessary
+    let shared = Rc::new(Foo);
+    assert_eq!(into_farewell(shared.clone()), "Goodbye, Foo!");
+    assert_eq!(into_farewell(Arc::new(Foo)), "Goodbye, Foo!");
+
+    // Default implementations are forwarded where possible, and inherited otherwise
+    assert_eq!(into_shout(Box::new(Foo)), "FOO!");
+    assert_eq!(into_shout(&Foo), "FOO");
+    assert_eq!(into_shout(Rc::new(Foo)), "FOO");
+}
@@ -0,0 +1,63 @@
+//  PARKING LOT.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:35:36
+//  Last edited:
+//    17 Oct 2026, 07:35:36
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for `parking_lot`'s guards with the
+//!   `pointer_impls`-macro.
+//
+
+use auto_traits::pointer_impls;
+use parking_lot::{Mutex, RwLock};
+
+
+/***** TRAITS *****/
+// `parking_lot` has to be asked for explicitly, and is found under whatever name this crate depends
+// on it.
+#[pointer_impls(impl parking_lot)]
+trait Counter {
+    fn count(&self) -> u32;
+
+    fn increment(&mut self);
+}
+
+
+// Let's implement it for some object.
+struct Foo(u32);
+impl Counter for Foo {
+    fn count(&self) -> u32 { self.0 }
+
+    fn increment(&mut self) { self.0 += 1; }
+}
+
+
+
+// This method is just to showcase for what `Counter` is implemented
+fn increment(mut counter: impl Counter) -> u32 {
+    counter.increment();
+    counter.count()
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // The default types are still there...
+    assert_eq!(increment(Box::new(Foo(0))), 1);
+
+    // ...but now also `parking_lot`'s guards
+    let mutex = Mutex::new(Foo(0));
+    assert_eq!(increment(mutex.lock()), 1);
+    assert_eq!(increment(mutex.lock()), 2);
+    let lock = RwLock::new(Foo(0));
+    assert_eq!(increment(lock.write()), 1);
+    assert_eq!(lock.read().count(), 1);
+}
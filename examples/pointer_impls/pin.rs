@@ -0,0 +1,119 @@
+//  PIN.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:33:13
+//  Last edited:
+//    17 Oct 2026, 07:33:13
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how the `pointer_impls`-macro can implement traits for any
+//!   pinned pointer using the `pin` group.
+//
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::Poll;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Implemented for `Pin<P>` whenever `P` dereferences to something implementing it.
+#[pointer_impls(impl pin)]
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+// Pinned receivers are projected through the pin, so these work even if the pinned value isn't
+// `Unpin`. Plain `&mut self` methods do require it, though.
+#[pointer_impls(impl pin)]
+trait Countdown {
+    fn poll_next(self: Pin<&mut Self>) -> Poll<u32>;
+
+    fn peek(self: Pin<&Self>) -> u32;
+}
+#[pointer_impls(impl pin)]
+trait Reset {
+    fn reset(&mut self, value: u32);
+}
+
+
+// Let's implement it for some objects.
+struct Foo(u32);
+impl Describe for Foo {
+    fn describe(&self) -> String { format!("Foo({})", self.0) }
+}
+impl Countdown for Foo {
+    fn poll_next(mut self: Pin<&mut Self>) -> Poll<u32> {
+        if self.0 > 0 {
+            self.0 -= 1;
+            Poll::Pending
+        } else {
+            Poll::Ready(0)
+        }
+    }
+
+    fn peek(self: Pin<&Self>) -> u32 { self.0 }
+}
+impl Reset for Foo {
+    fn reset(&mut self, value: u32) { self.0 = value; }
+}
+
+// This one may not be moved once pinned.
+struct Pinned(u32, PhantomPinned);
+impl Countdown for Pinned {
+    fn poll_next(self: Pin<&mut Self>) -> Poll<u32> {
+        // SAFETY: We don't move out of `self`
+        let this: &mut Self = unsafe { self.get_unchecked_mut() };
+        if this.0 > 0 {
+            this.0 -= 1;
+            Poll::Pending
+        } else {
+            Poll::Ready(0)
+        }
+    }
+
+    fn peek(self: Pin<&Self>) -> u32 { self.0 }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn describe<D: Describe>(describe: D) -> String { describe.describe() }
+fn countdown<C: Countdown + Unpin>(mut countdown: C) -> u32 {
+    let mut polls: u32 = 0;
+    while Pin::new(&mut countdown).poll_next().is_pending() {
+        polls += 1;
+    }
+    assert_eq!(Pin::new(&countdown).peek(), 0);
+    polls
+}
+fn reset<R: Reset>(mut reset: R) { reset.reset(3) }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Any pinned pointer can be used
+    assert_eq!(describe(Box::pin(Foo(42))), "Foo(42)");
+    assert_eq!(describe(Rc::pin(Foo(42))), "Foo(42)");
+    assert_eq!(describe(Pin::new(&Foo(42))), "Foo(42)");
+
+    // Pinned receivers don't need the pinned value to be `Unpin`
+    assert_eq!(countdown(Box::pin(Foo(3))), 3);
+    assert_eq!(countdown(Box::pin(Pinned(3, PhantomPinned))), 3);
+    let mut value = Foo(3);
+    assert_eq!(countdown(Pin::new(&mut value)), 3);
+    assert_eq!(value.0, 0);
+
+    // But normal mutable methods do
+    let mut value = Foo(0);
+    reset(Pin::new(&mut value));
+    assert_eq!(value.0, 3);
+}
@@ -0,0 +1,117 @@
+//  RECEIVERS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:11:41
+//  Last edited:
+//    17 Oct 2026, 07:11:41
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `pointer_impls`-macro also works for methods with
+//!   explicitly typed `self`-receivers.
+//
+
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// A poll-style trait. This is implemented for mutable pointers, as long as `T: Unpin`.
+#[pointer_impls]
+trait Countdown {
+    fn poll_next(self: Pin<&mut Self>) -> Poll<u32>;
+
+    fn peek(self: Pin<&Self>) -> u32;
+}
+
+// Consuming methods on boxes. This is only implemented for `Box` itself.
+#[pointer_impls]
+trait Consume {
+    fn consume(self: Box<Self>) -> String;
+}
+
+// Likewise for shared pointers
+#[pointer_impls]
+trait Share {
+    fn share_rc(self: Rc<Self>) -> String;
+}
+#[pointer_impls]
+trait ShareArc {
+    fn share_arc(self: Arc<Self>) -> String;
+}
+
+
+// Let's implement it for some object.
+struct Foo(u32);
+impl Countdown for Foo {
+    fn poll_next(mut self: Pin<&mut Self>) -> Poll<u32> {
+        if self.0 > 0 {
+            self.0 -= 1;
+            Poll::Pending
+        } else {
+            Poll::Ready(0)
+        }
+    }
+
+    fn peek(self: Pin<&Self>) -> u32 { self.0 }
+}
+impl Consume for Foo {
+    fn consume(self: Box<Self>) -> String { format!("Consumed {}", self.0) }
+}
+impl Share for Foo {
+    fn share_rc(self: Rc<Self>) -> String { format!("Shared {} in an Rc", self.0) }
+}
+impl ShareArc for Foo {
+    fn share_arc(self: Arc<Self>) -> String { format!("Shared {} in an Arc", self.0) }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn countdown<C: Countdown + Unpin>(mut countdown: C) -> u32 {
+    let mut polls: u32 = 0;
+    while Pin::new(&mut countdown).poll_next().is_pending() {
+        polls += 1;
+    }
+    assert_eq!(Pin::new(&countdown).peek(), 0);
+    polls
+}
+fn consume<C: Consume + ?Sized>(consume: Box<C>) -> String { consume.consume() }
+fn share_rc<S: Share + ?Sized>(share: Rc<S>) -> String { share.share_rc() }
+fn share_arc<S: ShareArc + ?Sized>(share: Arc<S>) -> String { share.share_arc() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Pinned receivers are projected through any mutable pointer
+    assert_eq!(countdown(Foo(3)), 3);
+    let mut value = Foo(3);
+    assert_eq!(countdown(&mut value), 3);
+    assert_eq!(value.0, 0);
+    assert_eq!(countdown(Box::new(Foo(3))), 3);
+    let cell = RefCell::new(Foo(3));
+    assert_eq!(countdown(cell.borrow_mut()), 3);
+    let mutex = Mutex::new(Foo(3));
+    assert_eq!(countdown(mutex.lock().unwrap()), 3);
+
+    // Boxed receivers only work through `Box` itself
+    assert_eq!(consume(Box::new(Foo(42))), "Consumed 42");
+    assert_eq!(consume(Box::new(Box::new(Foo(42)))), "Consumed 42");
+    assert_eq!(consume(Box::new(Box::new(Foo(42)) as Box<dyn Consume>)), "Consumed 42");
+
+    // And shared ones through their own pointer
+    let rc = Rc::new(Rc::new(Foo(42)));
+    assert_eq!(share_rc(rc.clone()), "Shared 42 in an Rc");
+    assert_eq!(share_rc(rc), "Shared 42 in an Rc");
+    assert_eq!(share_arc(Arc::new(Arc::new(Foo(42)))), "Shared 42 in an Arc");
+}
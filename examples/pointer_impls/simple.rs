@@ -4,7 +4,7 @@
 //  Created:
 //    13 Dec 2024, 14:44:49
 //  Last edited:
-//    16 Dec 2024, 15:26:28
+//    17 Oct 2026, 07:35:36
 //  Auto updated?
 //    Yes
 //
@@ -60,10 +60,4 @@ fn main() {
     hello_world(Mutex::new(Foo).lock().unwrap());
     hello_world(RwLock::new(Foo).read().unwrap());
     hello_world(RwLock::new(Foo).write().unwrap());
-    #[cfg(feature = "parking_lot")]
-    hello_world(parking_lot::Mutex::new(Foo).lock());
-    #[cfg(feature = "parking_lot")]
-    hello_world(parking_lot::RwLock::new(Foo).read());
-    #[cfg(feature = "parking_lot")]
-    hello_world(parking_lot::RwLock::new(Foo).write());
 }
@@ -0,0 +1,94 @@
+//  STD EXTRA.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:25:50
+//  Last edited:
+//    17 Oct 2026, 07:25:50
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the opt-in groups of standard library types supported by the
+//!   `pointer_impls`-macro.
+//
+
+use std::borrow::Cow;
+use std::cell::LazyCell;
+use std::mem::ManuallyDrop;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::LazyLock;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Define some trait - with pointer implementations for the additional types!
+#[pointer_impls(impl cow, impl manually_drop, impl assert_unwind_safe, impl lazy, impl pin_box)]
+trait HelloWorld {
+    fn hello_world(&self) -> String;
+}
+
+// These are only implemented for types with mutable access (and `Pin<Box<_>>` only if `_: Unpin`)
+#[pointer_impls(unimpl *, impl manually_drop, impl assert_unwind_safe, impl pin_box)]
+trait Counter {
+    fn count(&mut self) -> usize;
+}
+
+// And these only for types that own their contents
+#[pointer_impls(unimpl *, impl manually_drop, impl assert_unwind_safe)]
+trait IntoCount {
+    fn into_count(self) -> usize;
+}
+
+
+// Let's implement it for some object.
+#[derive(Clone)]
+struct Foo(usize);
+impl HelloWorld for Foo {
+    fn hello_world(&self) -> String { "Hello, world!".into() }
+}
+impl Counter for Foo {
+    fn count(&mut self) -> usize {
+        self.0 += 1;
+        self.0
+    }
+}
+impl IntoCount for Foo {
+    fn into_count(self) -> usize { self.0 }
+}
+
+
+
+// These methods are just to showcase for what `HelloWorld` and `Counter` are implemented
+fn hello_world(helloer: impl HelloWorld) -> String { helloer.hello_world() }
+fn count(mut counter: impl Counter) -> usize { counter.count() }
+fn into_count(counter: impl IntoCount) -> usize { counter.into_count() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+static LAZY: LazyLock<Foo> = LazyLock::new(|| Foo(0));
+
+fn main() {
+    // The additional types are supported...
+    assert_eq!(hello_world(Cow::Borrowed(&Foo(0))), "Hello, world!");
+    assert_eq!(hello_world(Cow::<Foo>::Owned(Foo(0))), "Hello, world!");
+    assert_eq!(hello_world(ManuallyDrop::new(Foo(0))), "Hello, world!");
+    assert_eq!(hello_world(AssertUnwindSafe(Foo(0))), "Hello, world!");
+    assert_eq!(hello_world(&*LAZY), "Hello, world!");
+    assert_eq!(hello_world(LazyCell::new(|| Foo(0))), "Hello, world!");
+    assert_eq!(hello_world(Box::pin(Foo(0))), "Hello, world!");
+    let unsized_pin: Pin<Box<dyn HelloWorld>> = Box::pin(Foo(0));
+    assert_eq!(hello_world(unsized_pin), "Hello, world!");
+
+    // ...including the mutable ones
+    assert_eq!(count(ManuallyDrop::new(Foo(0))), 1);
+    assert_eq!(count(AssertUnwindSafe(Foo(1))), 2);
+    assert_eq!(count(Box::pin(Foo(2))), 3);
+    assert_eq!(into_count(ManuallyDrop::new(Foo(3))), 3);
+    assert_eq!(into_count(AssertUnwindSafe(Foo(4))), 4);
+}
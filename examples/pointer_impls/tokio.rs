@@ -0,0 +1,93 @@
+//  TOKIO.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:37:35
+//  Last edited:
+//    17 Oct 2026, 07:37:35
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for `tokio`'s lock guards with the
+//!   `pointer_impls`-macro.
+//
+
+use std::sync::Arc;
+
+use auto_traits::pointer_impls;
+use tokio::sync::{Mutex, MutexGuard, OwnedMutexGuard, OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock};
+
+
+/***** TRAITS *****/
+// `tokio`'s guards are implemented when asked for.
+#[pointer_impls(impl tokio)]
+trait Counter {
+    fn count(&self) -> u32;
+
+    fn increment(&mut self);
+}
+
+// Read guards only implement traits without `&mut self`.
+#[pointer_impls(impl tokio)]
+trait Count {
+    fn count(&self) -> u32;
+}
+
+// Also for unsized types.
+#[pointer_impls(impl tokio)]
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+
+// Let's implement it for some object.
+struct Foo(u32);
+impl Counter for Foo {
+    fn count(&self) -> u32 { self.0 }
+
+    fn increment(&mut self) { self.0 += 1; }
+}
+impl Count for Foo {
+    fn count(&self) -> u32 { self.0 }
+}
+impl Describe for Foo {
+    fn describe(&self) -> String { format!("Foo({})", self.0) }
+}
+
+
+
+// These methods are just to showcase for what the traits are implemented
+fn increment(mut counter: impl Counter) -> u32 {
+    counter.increment();
+    counter.count()
+}
+fn count(count: impl Count) -> u32 { count.count() }
+fn describe(describe: impl Describe) -> String { describe.describe() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Borrowed guards
+    let mutex = Mutex::new(Foo(0));
+    assert_eq!(increment(mutex.blocking_lock()), 1);
+    assert_eq!(increment(MutexGuard::map(mutex.blocking_lock(), |inner| inner)), 2);
+    let lock = RwLock::new(Foo(0));
+    assert_eq!(increment(lock.blocking_write()), 1);
+    assert_eq!(count(lock.blocking_read()), 1);
+
+    // Owned guards
+    let mutex = Arc::new(Mutex::new(Foo(0)));
+    assert_eq!(increment(mutex.clone().blocking_lock_owned()), 1);
+    assert_eq!(increment(OwnedMutexGuard::map(mutex.clone().blocking_lock_owned(), |inner| inner)), 2);
+    let lock = Arc::new(RwLock::new((Foo(0), Foo(42))));
+    assert_eq!(increment(OwnedRwLockWriteGuard::map(lock.clone().try_write_owned().unwrap(), |(_, second)| second)), 43);
+    assert_eq!(count(OwnedRwLockReadGuard::map(lock.clone().try_read_owned().unwrap(), |(first, _)| first)), 0);
+
+    // Unsized types work too
+    let lock: Arc<RwLock<dyn Describe>> = Arc::new(RwLock::new(Foo(42)));
+    assert_eq!(describe(lock.try_read_owned().unwrap()), "Foo(42)");
+}
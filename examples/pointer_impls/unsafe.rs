@@ -0,0 +1,63 @@
+//  UNSAFE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:18:43
+//  Last edited:
+//    17 Oct 2026, 07:18:43
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `pointer_impls`-macro also works for unsafe traits
+//!   and methods.
+//
+
+use std::sync::Arc;
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Define some unsafe trait - with pointer implementations!
+/// # Safety
+/// Implementors must return a pointer that is valid for reads for at least `len` bytes.
+#[pointer_impls]
+unsafe trait Buffer {
+    fn as_ptr(&self) -> *const u8;
+
+    /// # Safety
+    /// `idx` must be in bounds of the buffer.
+    unsafe fn get_unchecked(&self, idx: usize) -> u8;
+}
+
+
+// Let's implement it for some object.
+struct Foo([u8; 4]);
+unsafe impl Buffer for Foo {
+    fn as_ptr(&self) -> *const u8 { self.0.as_ptr() }
+
+    unsafe fn get_unchecked(&self, idx: usize) -> u8 { unsafe { *self.0.get_unchecked(idx) } }
+}
+
+
+
+// This method is just to showcase for what `Buffer` is implemented
+fn second(buffer: impl Buffer) -> u8 {
+    // SAFETY: All buffers in this example are 4 bytes long
+    unsafe { buffer.get_unchecked(1) }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Both the impls and the calls are unsafe where necessary
+    let buffer = Foo([1, 2, 3, 4]);
+    assert_eq!(second(&buffer), 2);
+    assert_eq!(second(Box::new(Foo([1, 2, 3, 4]))), 2);
+    assert_eq!(second(Arc::new(Foo([1, 2, 3, 4]))), 2);
+    assert_eq!(Buffer::as_ptr(&&buffer), buffer.as_ptr());
+}
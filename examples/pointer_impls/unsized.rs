@@ -0,0 +1,110 @@
+//  UNSIZED.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 07:07:19
+//  Last edited:
+//    17 Oct 2026, 07:07:19
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that the `pointer_impls`-macro also generates impls for
+//!   unsized types, like trait objects.
+//
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+
+use auto_traits::pointer_impls;
+
+
+/***** TRAITS *****/
+// Define some trait - with pointer implementations!
+#[pointer_impls]
+trait HelloWorld {
+    fn hello(&self, what: &str) -> String;
+
+    /// This is not available on `dyn HelloWorld`, and hence inherited for unsized `T`s.
+    fn hello_world(&self) -> String
+    where
+        Self: Sized,
+    {
+        self.hello("world")
+    }
+}
+
+// Also one for mutable pointers
+#[pointer_impls]
+trait Counter {
+    fn count(&mut self) -> usize;
+}
+
+// This one can't be forwarded to unsized types, so is only implemented for sized ones.
+#[pointer_impls]
+trait Named {
+    fn name(&self) -> &str
+    where
+        Self: Sized;
+}
+
+
+// Let's implement it for some object.
+struct Foo(usize);
+impl HelloWorld for Foo {
+    fn hello(&self, what: &str) -> String { format!("Hello, {what}!") }
+}
+impl Counter for Foo {
+    fn count(&mut self) -> usize {
+        self.0 += 1;
+        self.0
+    }
+}
+impl Named for Foo {
+    fn name(&self) -> &str { "Foo" }
+}
+
+
+
+// These methods are just to showcase for what `HelloWorld` and `Counter` are implemented
+fn hello_world(helloer: impl HelloWorld) -> String { helloer.hello_world() }
+fn count(mut counter: impl Counter) -> usize { counter.count() }
+fn name(named: impl Named) -> String { named.name().into() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Every default pointer works over trait objects
+    let mut obj = Foo(0);
+    assert_eq!(hello_world(&obj as &dyn HelloWorld), "Hello, world!");
+    assert_eq!(hello_world(&mut obj as &mut dyn HelloWorld), "Hello, world!");
+    assert_eq!(hello_world(Box::new(Foo(0)) as Box<dyn HelloWorld>), "Hello, world!");
+    assert_eq!(hello_world(Rc::new(Foo(0)) as Rc<dyn HelloWorld>), "Hello, world!");
+    assert_eq!(hello_world(Arc::new(Foo(0)) as Arc<dyn HelloWorld>), "Hello, world!");
+    let cell: &RefCell<dyn HelloWorld> = &RefCell::new(Foo(0));
+    assert_eq!(hello_world(cell.borrow()), "Hello, world!");
+    assert_eq!(hello_world(cell.borrow_mut()), "Hello, world!");
+    let mutex: &Mutex<dyn HelloWorld> = &Mutex::new(Foo(0));
+    assert_eq!(hello_world(mutex.lock().unwrap()), "Hello, world!");
+    let rwlock: &RwLock<dyn HelloWorld> = &RwLock::new(Foo(0));
+    assert_eq!(hello_world(rwlock.read().unwrap()), "Hello, world!");
+    assert_eq!(hello_world(rwlock.write().unwrap()), "Hello, world!");
+
+    // Including the mutable ones
+    assert_eq!(count(&mut obj as &mut dyn Counter), 1);
+    assert_eq!(count(Box::new(Foo(1)) as Box<dyn Counter>), 2);
+    let cell: &RefCell<dyn Counter> = &RefCell::new(Foo(2));
+    assert_eq!(count(cell.borrow_mut()), 3);
+    let mutex: &Mutex<dyn Counter> = &Mutex::new(Foo(3));
+    assert_eq!(count(mutex.lock().unwrap()), 4);
+    let rwlock: &RwLock<dyn Counter> = &RwLock::new(Foo(4));
+    assert_eq!(count(rwlock.write().unwrap()), 5);
+
+    // While sized-only traits still work for sized types
+    assert_eq!(name(&obj), "Foo");
+    assert_eq!(name(Box::new(Foo(0))), "Foo");
+}
@@ -0,0 +1,103 @@
+//  OBSERVERS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:07:40
+//  Last edited:
+//    17 Oct 2026, 09:07:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to implement traits for tuples with the `tuple_impls`-macro.
+//
+
+use auto_traits::tuple_impls;
+
+
+/***** TRAITS *****/
+// Every call on a tuple fans out to its elements, and their results are combined per method.
+#[tuple_impls(max = 4)]
+trait Observer {
+    // Associated types have to be given...
+    #[tuple_impl(type = &'static str)]
+    type Event;
+    // ...while constants can be combined too.
+    #[tuple_impl(reduce = sum)]
+    const COUNT: usize;
+
+    // Methods returning nothing are called in order.
+    fn notify(&mut self, event: Self::Event);
+    #[tuple_impl(reduce = and)]
+    fn is_interested(&self, event: &Self::Event) -> bool;
+    #[tuple_impl(reduce = or)]
+    fn has_seen(&self, event: &Self::Event) -> bool;
+    #[tuple_impl(reduce = sum)]
+    fn seen(&self) -> usize;
+    #[tuple_impl(reduce = first)]
+    fn name(&self) -> String;
+    #[tuple_impl(reduce = collect)]
+    fn history(&self) -> Vec<String>;
+    // Methods returning `Self` build a tuple of what the elements return.
+    fn new() -> Self
+    where
+        Self: Sized;
+}
+
+
+// Let's implement it for some objects.
+#[derive(Debug, Default, PartialEq)]
+struct Logger(Vec<String>);
+impl Observer for Logger {
+    type Event = &'static str;
+    const COUNT: usize = 1;
+
+    fn notify(&mut self, event: Self::Event) { self.0.push(event.into()); }
+    fn is_interested(&self, _event: &Self::Event) -> bool { true }
+    fn has_seen(&self, event: &Self::Event) -> bool { self.0.iter().any(|e| e == event) }
+    fn seen(&self) -> usize { self.0.len() }
+    fn name(&self) -> String { "logger".into() }
+    fn history(&self) -> Vec<String> { self.0.clone() }
+    fn new() -> Self { Self::default() }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Counter(usize);
+impl Observer for Counter {
+    type Event = &'static str;
+    const COUNT: usize = 1;
+
+    fn notify(&mut self, _event: Self::Event) { self.0 += 1; }
+    fn is_interested(&self, event: &Self::Event) -> bool { !event.is_empty() }
+    fn has_seen(&self, _event: &Self::Event) -> bool { false }
+    fn seen(&self) -> usize { self.0 }
+    fn name(&self) -> String { "counter".into() }
+    fn history(&self) -> Vec<String> { vec![format!("{} events", self.0)] }
+    fn new() -> Self { Self::default() }
+}
+
+
+
+// These methods are just to showcase for what the trait is implemented
+fn notify(observer: &mut impl Observer<Event = &'static str>) { observer.notify("hello") }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let mut observers = <(Logger, Counter, Counter)>::new();
+    assert_eq!(observers, (Logger(vec![]), Counter(0), Counter(0)));
+    assert_eq!(<(Logger, Counter, Counter) as Observer>::COUNT, 3);
+
+    notify(&mut observers);
+    notify(&mut observers);
+    assert!(observers.is_interested(&"world"));
+    assert!(!observers.is_interested(&""));
+    assert!(observers.has_seen(&"hello"));
+    assert!(!observers.has_seen(&"world"));
+    assert_eq!(observers.seen(), 6);
+    assert_eq!(observers.name(), "logger");
+    assert_eq!(observers.history(), ["hello", "hello", "2 events", "2 events"]);
+}
@@ -0,0 +1,378 @@
+//  COLLECTION IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:18:09
+//  Last edited:
+//    17 Oct 2026, 09:18:09
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines an attribute macro for automatically implementing traits for
+//!   standard collections of implementors.
+//
+
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::token::Brace;
+use syn::{
+    Error, FnArg, Ident, ItemTrait, Pat, ReturnType, Token, TraitItem, TraitItemConst, TraitItemFn, TraitItemType, Type, WhereClause,
+    WherePredicate, parse_quote,
+};
+
+use crate::pointer_impls::{
+    ImplsToDo, ReceiverKind, body_to_tokens, free_ident, generic_args, has_pending_impls, mentions_self, passing_args, returns_unit,
+};
+use crate::tuple_impls::{Reduce, ReduceAttributes};
+
+
+/***** PARSING *****/
+/// Defines the collections we can implement for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Collection {
+    /// `Vec<T>`
+    Vec,
+    /// `VecDeque<T>`
+    VecDeque,
+    /// `[T]`
+    Slice,
+    /// `[T; N]`
+    Array,
+}
+impl Collection {
+    /// All the collections, in the order we implement them.
+    const ALL: [Self; 4] = [Self::Vec, Self::VecDeque, Self::Slice, Self::Array];
+
+    /// Returns whether this collection needs `alloc` (or `std`).
+    #[inline]
+    fn allocates(self) -> bool { matches!(self, Self::Vec | Self::VecDeque) }
+}
+
+/// Specifies the attributes we're parsing from the `#[collection_impls(...)]`-attribute.
+struct CollectionAttributes {
+    /// The collections to implement for.
+    collections: Vec<Collection>,
+    /// The crate through which to refer to the allocating collections, if any.
+    library:     Option<&'static str>,
+}
+impl Parse for CollectionAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut collections: Vec<(Collection, Span)> = Vec::new();
+        let mut library: Option<&'static str> = Some("std");
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key == "core" {
+                library = None;
+            } else if key == "alloc" {
+                library = Some("alloc");
+            } else if key == "vec" {
+                collections.push((Collection::Vec, key.span()));
+            } else if key == "vec_deque" {
+                collections.push((Collection::VecDeque, key.span()));
+            } else if key == "slice" {
+                collections.push((Collection::Slice, key.span()));
+            } else if key == "array" {
+                collections.push((Collection::Array, key.span()));
+            } else {
+                return Err(Error::new(
+                    key.span(),
+                    format!("Unknown collection_impls attribute '{key}' (expected 'vec', 'vec_deque', 'slice', 'array', 'core' or 'alloc')"),
+                ));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        // If none are given, implement for everything available
+        if collections.is_empty() {
+            return Ok(Self { collections: Collection::ALL.into_iter().filter(|c| library.is_some() || !c.allocates()).collect(), library });
+        }
+        if library.is_none() {
+            if let Some((_, span)) = collections.iter().find(|(c, _)| c.allocates()) {
+                return Err(Error::new(*span, "Cannot implement for allocating collections in `core` mode (use `alloc` instead)"));
+            }
+        }
+        Ok(Self { collections: collections.into_iter().map(|(c, _)| c).collect(), library })
+    }
+}
+
+
+
+
+
+/***** GENERATION *****/
+/// Implements the main struct doing the heavy lifting.
+struct Generator {
+    /// What we parsed from the attribute stream.
+    attrs: CollectionAttributes,
+    /// What we parsed from the item stream.
+    todo: ImplsToDo,
+    /// What we parsed from the `#[collection_impl(...)]`-attributes on its items.
+    item_attrs: HashMap<usize, ReduceAttributes>,
+}
+impl Generator {
+    /// Checks whether every item of the trait can be implemented, and inherits those that can't if
+    /// possible.
+    ///
+    /// # Errors
+    /// This function errors if an item can't be implemented and has no default.
+    fn check(&mut self) -> syn::Result<()> {
+        let name: &Ident = &self.todo.def.ident;
+        for (i, item) in self.todo.def.items.iter().enumerate() {
+            if !self.todo.item_mask[i] {
+                continue;
+            }
+            let attrs: Option<&ReduceAttributes> = self.item_attrs.get(&i);
+            let reduce: Option<(Reduce, Span)> = attrs.and_then(|attrs| attrs.reduce);
+            match item {
+                TraitItem::Type(ty) => {
+                    if let Some((_, span)) = reduce {
+                        return Err(Error::new(span, "Associated types cannot be reduced (give them with `#[collection_impl(type = ...)]` instead)"));
+                    }
+                    if attrs.is_some_and(|attrs| attrs.value.is_some()) {
+                        return Err(Error::new(ty.ident.span(), "Associated types are given with `#[collection_impl(type = ...)]`"));
+                    }
+                    if !ty.generics.params.is_empty() && attrs.is_some_and(|attrs| attrs.ty.is_some()) {
+                        return Err(Error::new(ty.ident.span(), format!("Cannot give generic associated type '{}' for collections", ty.ident)));
+                    }
+                },
+
+                TraitItem::Const(c) => {
+                    if let Some((_, span)) = reduce {
+                        return Err(Error::new(
+                            span,
+                            "Associated constants cannot be reduced, because the number of elements is only known at runtime (give them with \
+                             `#[collection_impl(value = ...)]` instead)",
+                        ));
+                    }
+                    if attrs.is_some_and(|attrs| attrs.ty.is_some()) {
+                        return Err(Error::new(c.ident.span(), "Associated constants are given with `#[collection_impl(value = ...)]`"));
+                    }
+                },
+
+                TraitItem::Fn(f) => {
+                    let sig = &f.sig;
+                    let mentions_self: bool = sig.inputs.iter().any(|arg| matches!(arg, FnArg::Typed(t) if mentions_self(&t.ty)))
+                        || matches!(&sig.output, ReturnType::Type(_, ty) if mentions_self(ty));
+                    let reason: Option<(String, Span)> = match (self.todo.receivers.get(&i), reduce) {
+                        (None, _) => Some(("it has no receiver, so there are no elements to call it on".into(), sig.ident.span())),
+                        (Some(ReceiverKind::Ref | ReceiverKind::Mut), _) if mentions_self => {
+                            Some(("its signature mentions `Self`".into(), sig.ident.span()))
+                        },
+                        (Some(ReceiverKind::Ref | ReceiverKind::Mut), Some((Reduce::First, span))) => {
+                            return Err(Error::new(span, "Cannot reduce with `first` for collections, because they might be empty"));
+                        },
+                        (Some(ReceiverKind::Ref | ReceiverKind::Mut), Some((_, span))) if returns_unit(&sig.output) => {
+                            return Err(Error::new(span, format!("Method '{}' returns nothing, so there is nothing to reduce", sig.ident)));
+                        },
+                        (Some(ReceiverKind::Ref | ReceiverKind::Mut), None) if !returns_unit(&sig.output) => Some((
+                            "it returns a value (give how to combine them with `#[collection_impl(reduce = ...)]`)".into(),
+                            sig.ident.span(),
+                        )),
+                        (Some(ReceiverKind::Ref | ReceiverKind::Mut), _) => None,
+                        (Some(kind), _) => {
+                            Some((format!("it takes `{kind}`, which can't be split into the collection's elements"), sig.inputs.first().unwrap().span()))
+                        },
+                    };
+                    if let Some((reason, span)) = reason {
+                        if f.default.is_some() {
+                            self.todo.item_mask.set(i, false);
+                            continue;
+                        }
+                        return Err(Error::new(span, format!("Cannot implement method '{}' of trait '{name}' for collections because {reason}", sig.ident)));
+                    }
+                },
+
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls a method on every element of the collection and combines the results.
+    ///
+    /// # Arguments
+    /// - `elem`: The identifier that every element is bound to in `call`.
+    /// - `call`: The expression that calls the method on one element.
+    /// - `reduce`: How to combine the results, if at all.
+    /// - `output`: What the method returns.
+    ///
+    /// # Returns
+    /// An expression that produces the combined result.
+    fn reduce(elem: &Ident, call: &TokenStream2, reduce: Option<Reduce>, output: &ReturnType) -> TokenStream2 {
+        // We write loops instead of using iterators so `.await` can be used in the calls
+        let this = Ident::new("self", Span::call_site());
+        let acc = Ident::new("acc", Span::mixed_site());
+        let iter = quote! { #elem in ::core::iter::IntoIterator::into_iter(#this) };
+        let ret: TokenStream2 = match output {
+            ReturnType::Default => quote! { () },
+            ReturnType::Type(_, ty) => ty.to_token_stream(),
+        };
+        match reduce {
+            None => quote! { for #iter { #call; } },
+            Some(Reduce::And) => quote! { for #iter { if !#call { return false; } } true },
+            Some(Reduce::Or) => quote! { for #iter { if #call { return true; } } false },
+            Some(Reduce::Sum) => quote! {
+                let mut #acc: #ret = ::core::iter::Sum::sum(::core::iter::empty::<#ret>());
+                for #iter { #acc = ::core::ops::Add::add(#acc, #call); }
+                #acc
+            },
+            Some(Reduce::Collect) => quote! {
+                let mut #acc: #ret = ::core::iter::FromIterator::from_iter(::core::iter::empty());
+                for #iter { ::core::iter::Extend::extend(&mut #acc, #call); }
+                #acc
+            },
+            Some(Reduce::First) => unreachable!("Got `first` reducer even though it should have been rejected by Generator::check()"),
+        }
+    }
+
+    /// Generates the impl for one collection.
+    ///
+    /// # Arguments
+    /// - `collection`: The [`Collection`] to implement for.
+    /// - `tokens`: The [`TokenStream2`] to write the impl to.
+    fn impl_to_tokens(&self, collection: Collection, tokens: &mut TokenStream2) {
+        let def: &ItemTrait = &self.todo.def;
+        let name: &Ident = &def.ident;
+        let (_, trait_ty_gen, _) = def.generics.split_for_impl();
+        let trait_args: Vec<TokenStream2> = generic_args(&def.generics);
+        let t: Ident = free_ident(def, "T");
+        let n: Ident = free_ident(def, "N");
+
+        // Find the type to implement for
+        let library: Option<Ident> = self.attrs.library.map(|library| Ident::new(library, Span::call_site()));
+        let ty: TokenStream2 = match collection {
+            Collection::Vec => quote! { ::#library::vec::Vec<#t> },
+            Collection::VecDeque => quote! { ::#library::collections::VecDeque<#t> },
+            Collection::Slice => quote! { [#t] },
+            Collection::Array => quote! { [#t; #n] },
+        };
+
+        // The elements have to implement the trait with the associated types the user gave
+        let bindings: Vec<TokenStream2> = def
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| match item {
+                TraitItem::Type(ty) if self.todo.item_mask[i] => {
+                    let ident: &Ident = &ty.ident;
+                    let value: &Type = self.item_attrs.get(&i)?.ty.as_ref()?;
+                    Some(quote! { #ident = #value })
+                },
+                _ => None,
+            })
+            .collect();
+        let args: Vec<&TokenStream2> = trait_args.iter().chain(bindings.iter()).collect();
+        let mut generics = def.generics.clone();
+        generics.params.push(parse_quote! { #t });
+        if collection == Collection::Array {
+            generics.params.push(parse_quote! { const #n: usize });
+        }
+        let (impl_gen, _, _) = generics.split_for_impl();
+        let mut where_clause: WhereClause = def.generics.where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+        let bound: WherePredicate = if args.is_empty() { parse_quote! { #t: #name } } else { parse_quote! { #t: #name<#(#args),*> } };
+        where_clause.predicates.push(bound);
+
+        // Then generate the items
+        let mut items: Vec<TokenStream2> = Vec::with_capacity(self.todo.item_mask.count_ones());
+        for (i, item) in def.items.iter().enumerate() {
+            if !self.todo.item_mask[i] {
+                continue;
+            }
+            let attrs: Option<&ReduceAttributes> = self.item_attrs.get(&i);
+            let reduce: Option<Reduce> = attrs.and_then(|attrs| attrs.reduce.map(|(reduce, _)| reduce));
+            match item {
+                TraitItem::Type(TraitItemType { attrs: ty_attrs, type_token, ident, generics, semi_token, .. }) => {
+                    let (impl_gen, ty_gen, where_clause) = generics.split_for_impl();
+                    let value: TokenStream2 = match attrs.and_then(|attrs| attrs.ty.as_ref()) {
+                        Some(value) => value.to_token_stream(),
+                        None => quote! { <#t as #name #trait_ty_gen>::#ident #ty_gen },
+                    };
+                    items.push(quote! { #(#ty_attrs)* #type_token #ident #impl_gen = #value #where_clause #semi_token });
+                },
+
+                TraitItem::Const(TraitItemConst { attrs: c_attrs, const_token, ident, colon_token, ty, semi_token, .. }) => {
+                    let value: TokenStream2 = match attrs.and_then(|attrs| attrs.value.as_ref()) {
+                        Some(value) => value.to_token_stream(),
+                        None => quote! { <#t as #name #trait_ty_gen>::#ident },
+                    };
+                    items.push(quote! { #(#c_attrs)* #const_token #ident #colon_token #ty = #value #semi_token });
+                },
+
+                TraitItem::Fn(TraitItemFn { attrs: f_attrs, sig, default, .. }) => {
+                    let ident: &Ident = &sig.ident;
+
+                    // Owned arguments are cloned for every element, since we don't know which is last
+                    let passing_args: Punctuated<Pat, Token![,]> = passing_args(sig);
+                    let by_ref: Vec<bool> =
+                        sig.inputs.iter().filter_map(|arg| if let FnArg::Typed(t) = arg { Some(matches!(&*t.ty, Type::Reference(_))) } else { None }).collect();
+                    let call_generics: TokenStream2 = self.todo.call_generics(i, sig);
+                    let asyncness: Option<TokenStream2> = sig.asyncness.map(|_| quote! { .await });
+                    let elem = Ident::new("elem", Span::mixed_site());
+                    let args = passing_args.iter().zip(&by_ref).map(|(arg, by_ref)| {
+                        if *by_ref { arg.to_token_stream() } else { quote! { ::core::clone::Clone::clone(&#arg) } }
+                    });
+                    let call: TokenStream2 = quote! { <#t as #name #trait_ty_gen>::#ident #call_generics (#elem, #(#args),*) #asyncness };
+                    let body: TokenStream2 = Self::reduce(&elem, &call, reduce, &sig.output);
+
+                    // Unsafe methods can only be called in an unsafe block
+                    let brace_token: Brace = default.as_ref().map(|b| b.brace_token).unwrap_or_default();
+                    let mut tokens = quote! { #(#f_attrs)* #sig };
+                    body_to_tokens(sig, brace_token, TokenStream2::new(), body, &mut tokens);
+                    items.push(tokens);
+                },
+
+                _ => {},
+            }
+        }
+
+        // Now build the overall impl
+        let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+        tokens.extend(quote! { #unsafety impl #impl_gen #name #trait_ty_gen for #ty #where_clause { #(#items)* } });
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `collection_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + implementations for collections.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn collection_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    let attrs: CollectionAttributes = syn::parse2(attr)?;
+
+    // Take our own attributes off of the items first, and then parse the rest like usual
+    let mut def: ItemTrait = syn::parse2(item)?;
+    let item_attrs: HashMap<usize, ReduceAttributes> = ReduceAttributes::extract_items(&mut def, "collection_impl")?;
+    let mut todo: ImplsToDo = syn::parse2(def.to_token_stream())?;
+    todo.resolve_defaults(Default::default())?;
+
+    // Write the definition itself. If any of our other macros still has to run, it needs the
+    // `#[pointer_impl(...)]`-attributes.
+    let mut tokens: TokenStream2 = if has_pending_impls(&def) { def.to_token_stream() } else { todo.def.to_token_stream() };
+
+    // Then generate the impls for every collection
+    let mut generator = Generator { attrs, todo, item_attrs };
+    generator.check()?;
+    for collection in &generator.attrs.collections {
+        generator.impl_to_tokens(*collection, &mut tokens);
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,367 @@
+//  DELEGATE IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 08:04:06
+//  Last edited:
+//    17 Oct 2026, 09:07:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines attribute macros for automatically implementing traits for
+//!   newtypes by delegating to one of their fields.
+//
+
+use std::collections::HashSet;
+
+use proc_macro_crate::{FoundCrate, crate_name};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned as _;
+use syn::{
+    Error, Expr, ExprField, FnArg, GenericParam, Generics, Ident, Item, ItemStruct, ItemTrait, Member, Path, PathArguments, ReturnType, Token,
+    TraitItem, Type, Visibility, braced, parse_quote,
+};
+
+use crate::dispatch_impls::dispatch_to_variants;
+use crate::pointer_impls::{Accessors, Attributes, Generator, ImplsToDo, Ownership, TypeToImpl, has_pending_impls, mentions_self};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Finds the path by which the crate invoking the macro refers to this crate.
+///
+/// Unlike dependencies of pointer types, this never fails: if the crate can't be found (e.g., in
+/// our own examples), it's assumed to be available under its own name.
+///
+/// # Returns
+/// A [`Path`] that refers to this crate's root (e.g., `::auto_traits`).
+fn krate() -> Path {
+    match crate_name("auto-traits") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = Ident::new(&name, Span::call_site());
+            parse_quote! { ::#name }
+        },
+        Ok(FoundCrate::Itself) | Err(_) => parse_quote! { ::auto_traits },
+    }
+}
+
+/// Returns the name of the hidden macro describing the shape of a trait.
+///
+/// # Arguments
+/// - `ident`: The name of the trait.
+///
+/// # Returns
+/// An [`Ident`] with the name `__delegate_<ident>`.
+#[inline]
+fn shape_ident(ident: &Ident) -> Ident { format_ident!("__delegate_{}", ident) }
+
+/// Generates a call to the shape of a trait, which will implement it for the given item.
+///
+/// # Arguments
+/// - `path`: The path to the trait as given by the user.
+/// - `options`: The options to pass to [`__delegate()`] on top of the trait.
+/// - `item`: The item that the attribute spans, which is written as-is.
+/// - `stripped`: The item without any attributes, which is passed to [`__delegate()`].
+///
+/// # Returns
+/// A new [`TokenStream2`] with `item` and the call to the shape.
+pub(crate) fn call_shape(path: &Path, options: TokenStream2, item: TokenStream2, stripped: TokenStream2) -> TokenStream2 {
+    // The shape lives next to the trait
+    let mut shape: Path = path.clone();
+    let last = shape.segments.last_mut().unwrap();
+    last.ident = shape_ident(&last.ident);
+    quote! {
+        #item
+        #shape! { { #path } { #options } { #stripped } }
+    }
+}
+
+/// Finds the type of a field in a struct.
+///
+/// # Arguments
+/// - `item`: The [`ItemStruct`] to search.
+/// - `member`: The [`Member`] referring to the field.
+///
+/// # Returns
+/// The [`Type`] of the field.
+///
+/// # Errors
+/// This function errors if the struct has no such field.
+fn field_type<'s>(item: &'s ItemStruct, member: &Member) -> syn::Result<&'s Type> {
+    item.fields
+        .iter()
+        .enumerate()
+        .find(|(i, field)| match (member, &field.ident) {
+            (Member::Named(name), Some(ident)) => name == ident,
+            (Member::Unnamed(index), None) => index.index as usize == *i,
+            _ => false,
+        })
+        .map(|(_, field)| &field.ty)
+        .ok_or_else(|| Error::new(member.span(), format!("Struct '{}' has no field `{}`", item.ident, member.to_token_stream())))
+}
+
+
+
+
+
+/***** PARSING *****/
+/// Specifies the attributes we're parsing from the `#[delegate(...)]`-attribute.
+struct DelegateAttributes {
+    /// The path to the trait to implement.
+    path: Path,
+    /// The field to delegate to, if given.
+    to:   Option<Member>,
+}
+impl Parse for DelegateAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Parse the trait first
+        let path: Path = input.parse()?;
+        if let Some(seg) = path.segments.iter().find(|seg| !matches!(seg.arguments, PathArguments::None)) {
+            return Err(Error::new(
+                seg.arguments.span(),
+                "Generic arguments are not supported by `#[delegate(...)]` (the impl is already generic over those of the trait)",
+            ));
+        }
+
+        // Then the field, if any
+        let mut to: Option<Member> = None;
+        if input.parse::<Token![,]>().is_ok() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "to" {
+                return Err(Error::new(key.span(), "Expected 'to'"));
+            }
+            input.parse::<Token![=]>()?;
+            let expr: Expr = input.parse()?;
+            match expr {
+                Expr::Field(ExprField { base, member, .. }) if matches!(&*base, Expr::Path(p) if p.path.is_ident("self")) => to = Some(member),
+                expr => return Err(Error::new(expr.span(), "Expected a field of `self` to delegate to (e.g., `self.0`)")),
+            }
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Self { path, to })
+    }
+}
+
+/// Specifies what the shape of a trait passes to [`__delegate()`].
+struct DelegateInput {
+    /// The trait to implement, as defined.
+    def:     TokenStream2,
+    /// The path to the trait as given by the user.
+    path:    Path,
+    /// The options of the macro that called the shape (e.g., the field to delegate to).
+    options: TokenStream2,
+    /// The struct or enum to implement the trait for.
+    item:    Item,
+}
+impl Parse for DelegateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (def, path, options, item);
+        braced!(def in input);
+        braced!(path in input);
+        braced!(options in input);
+        braced!(item in input);
+        Ok(Self { def: def.parse()?, path: path.parse()?, options: options.parse()?, item: item.parse()? })
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `delegate_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + a hidden macro describing its shape.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn delegate_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    if !attr.is_empty() {
+        return Err(Error::new(attr.span(), "`#[delegate_impls]` does not accept any arguments"));
+    }
+
+    // Parse the trait, both as-is and to check it's something we can delegate
+    let mut def: ItemTrait = syn::parse2(item.clone())?;
+    let todo: ImplsToDo = syn::parse2(item)?;
+
+    // Write the definition itself. If any of our other macros still has to run, it needs the item
+    // attributes; otherwise, we remove them ourselves.
+    let mut tokens: TokenStream2 = if has_pending_impls(&def) { def.to_token_stream() } else { todo.def.to_token_stream() };
+
+    // Then generate the shape, which carries the trait (but not its attributes) to `#[delegate]`
+    let krate: Path = krate();
+    let shape: Ident = shape_ident(&def.ident);
+    let vis: TokenStream2 = match &def.vis {
+        Visibility::Inherited => TokenStream2::new(),
+        Visibility::Public(_) => quote! { pub(crate) },
+        vis @ Visibility::Restricted(_) => vis.to_token_stream(),
+    };
+    def.attrs.clear();
+    tokens.extend(quote! {
+        #[doc(hidden)]
+        macro_rules! #shape {
+            ($($args:tt)*) => { #krate::__delegate! { { #def } $($args)* } };
+        }
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        #vis use #shape;
+    });
+    Ok(tokens)
+}
+
+/// Actual implementation of the `delegate`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + a call to the shape of the trait.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn delegate(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    let DelegateAttributes { path, to } = syn::parse2(attr)?;
+    let item: ItemStruct = match syn::parse2(item)? {
+        Item::Struct(item) => item,
+        item => return Err(Error::new(item.span(), "`#[delegate(...)]` can only be used on structs")),
+    };
+
+    // Find the field to delegate to, which may be omitted for structs with only one
+    let member: Member = match to {
+        Some(member) => member,
+        None if item.fields.len() == 1 => item.fields.members().next().unwrap(),
+        None => {
+            return Err(Error::new(
+                item.ident.span(),
+                format!("Struct '{}' does not have exactly one field, so you have to give which to delegate to (e.g., `to = self.0`)", item.ident),
+            ));
+        },
+    };
+    field_type(&item, &member)?;
+
+    // Call the shape of the trait, which lives next to it
+    let mut stripped: ItemStruct = item.clone();
+    stripped.attrs.clear();
+    Ok(call_shape(&path, member.to_token_stream(), item.into_token_stream(), stripped.into_token_stream()))
+}
+
+/// Actual implementation of the `__delegate`-macro, which is called by the shape of a trait.
+///
+/// # Arguments
+/// - `input`: The definition of the trait, followed by what [`delegate()`] or
+///   [`dispatch_impls()`](crate::dispatch_impls::dispatch_impls()) passes to it.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the impl of the trait for the struct or enum.
+///
+/// # Errors
+/// This function may error if the trait cannot be delegated.
+pub fn __delegate(input: TokenStream2) -> Result<TokenStream2, Error> {
+    let DelegateInput { def, path, options, item } = syn::parse2(input)?;
+    let mut todo: ImplsToDo = syn::parse2(def)?;
+    todo.resolve_defaults(Default::default())?;
+    match item {
+        Item::Struct(item) => delegate_to_field(todo, path, syn::parse2(options)?, item),
+        Item::Enum(item) => dispatch_to_variants(todo, path, syn::parse2(options)?, item),
+        item => Err(Error::new(item.span(), "Can only delegate to structs or enums")),
+    }
+}
+
+/// Implements a trait for a struct by delegating to one of its fields.
+///
+/// # Arguments
+/// - `todo`: The [`ImplsToDo`] describing the trait.
+/// - `path`: The path to the trait as given by the user.
+/// - `member`: The [`Member`] referring to the field to delegate to.
+/// - `item`: The [`ItemStruct`] to implement the trait for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the impl of the trait for the struct.
+///
+/// # Errors
+/// This function may error if the trait cannot be delegated.
+fn delegate_to_field(mut todo: ImplsToDo, path: Path, member: Member, item: ItemStruct) -> Result<TokenStream2, Error> {
+    let field: Type = field_type(&item, &member)?.clone();
+
+    // The impl is for the struct with all of its generics, minus their defaults
+    let ident: &Ident = &item.ident;
+    let (_, ty_gen, where_clause) = item.generics.split_for_impl();
+    let mut generics: Generics = Generics { where_clause: None, ..item.generics.clone() };
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Type(t) => {
+                t.eq_token = None;
+                t.default = None;
+            },
+            GenericParam::Const(c) => {
+                c.eq_token = None;
+                c.default = None;
+            },
+            GenericParam::Lifetime(_) => {},
+        }
+    }
+    let this = Ident::new("self", Span::call_site());
+    let to_impl = TypeToImpl {
+        ty: parse_quote! { #ident #ty_gen },
+        mutable: true,
+        maybe_unsized: false,
+        ownership: Ownership::Unique,
+        generics: if generics.params.is_empty() { None } else { Some(generics) },
+        accessors: Accessors {
+            by_ref: Some(parse_quote! { &#this.#member }),
+            by_mut: Some(parse_quote! { &mut #this.#member }),
+            owned:  Some(parse_quote! { #this.#member }),
+        },
+        bounds: where_clause.map(|w| w.predicates.iter().cloned().collect()).unwrap_or_default(),
+        mut_bounds: Vec::new(),
+        target: Some(field.clone()),
+    };
+
+    // Check that every method can actually be delegated, and inherit those that can't if we can
+    for (i, item) in todo.def.items.iter().enumerate() {
+        let TraitItem::Fn(f) = item else { continue };
+        if !todo.item_mask[i] {
+            continue;
+        }
+
+        // It must take `self` in a way the field can be passed on, and not return or accept
+        // `Self` otherwise (since that's a different type for the field)
+        let reason: Option<String> = if let Some(receiver) = todo.receivers.get(&i) {
+            to_impl.access(*receiver, None, &field, &to_impl.ty).err().map(|reason| format!("it takes `{receiver}`, but {reason}"))
+        } else {
+            None
+        };
+        let reason: Option<String> = reason.or_else(|| {
+            let mut types = f.sig.inputs.iter().filter_map(|arg| match arg {
+                FnArg::Receiver(_) => None,
+                FnArg::Typed(t) => Some(&*t.ty),
+            });
+            let mentions: bool = types.any(mentions_self) || matches!(&f.sig.output, ReturnType::Type(_, ty) if mentions_self(ty));
+            mentions.then(|| format!("its signature mentions `Self`, which is '{ident}' instead of the field's type"))
+        });
+        if let Some(reason) = reason {
+            if f.default.is_some() {
+                todo.item_mask.set(i, false);
+                continue;
+            }
+            return Err(Error::new(f.sig.ident.span(), format!("Cannot delegate method '{}' to field `{}` because {}", f.sig.ident, member.to_token_stream(), reason)));
+        }
+    }
+
+    // Then generate only the impl, referring to the trait by its path
+    let mut attrs = Attributes::default();
+    attrs.generic = Ident::new("__DelegateT", Span::call_site());
+    attrs.types = HashSet::from([to_impl]);
+    let generator = Generator { attrs, todo, path: Some(path) };
+    let mut tokens = TokenStream2::new();
+    generator.impls_to_tokens(&mut tokens);
+    Ok(tokens)
+}
@@ -0,0 +1,412 @@
+//  DISPATCH IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 08:08:57
+//  Last edited:
+//    17 Oct 2026, 08:08:57
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines an attribute macro for automatically implementing traits for
+//!   enums by dispatching to their variants.
+//
+
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::token::Brace;
+use syn::{
+    Error, Expr, FnArg, Generics, Ident, Item, ItemEnum, Member, Pat, Path, PathArguments, ReturnType, Token, TraitItem, TraitItemConst,
+    TraitItemFn, TraitItemType, Type, WhereClause, WherePredicate, parse_quote,
+};
+
+use crate::delegate_impls::call_shape;
+use crate::pointer_impls::{
+    ImplsToDo, ReceiverKind, body_to_tokens, generic_args, inject_additional_types, mentions_self, passing_args, returns_self, strip_owned_mut,
+};
+
+
+/***** PARSING *****/
+/// Describes how to dispatch a method that can't simply be forwarded to the matched variant.
+#[derive(Clone)]
+enum Strategy {
+    /// Forward to the matched variant, and wrap the returned `Self` in that same variant.
+    Wrap(Span),
+    /// Forward to the impl of the given variant's type (e.g., for constructors).
+    Variant(Ident),
+}
+
+/// Specifies the items of the trait that the user gave explicitly.
+#[derive(Clone)]
+enum Override {
+    /// `type Foo<...> = ...`
+    Type { ident: Ident, generics: Generics, ty: Type },
+    /// `const FOO = ...`
+    Const { ident: Ident, value: Expr },
+    /// `fn foo = wrap` or `fn foo = Variant`
+    Fn { ident: Ident, strategy: Strategy },
+}
+impl Override {
+    /// Returns the name of the item overridden.
+    #[inline]
+    fn ident(&self) -> &Ident {
+        match self {
+            Self::Type { ident, .. } | Self::Const { ident, .. } | Self::Fn { ident, .. } => ident,
+        }
+    }
+}
+impl Parse for Override {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![type]) {
+            input.parse::<Token![type]>()?;
+            let ident: Ident = input.parse()?;
+            let generics: Generics = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Type { ident, generics, ty: input.parse()? })
+        } else if lookahead.peek(Token![const]) {
+            input.parse::<Token![const]>()?;
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Const { ident, value: input.parse()? })
+        } else if lookahead.peek(Token![fn]) {
+            input.parse::<Token![fn]>()?;
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let strategy: Ident = input.parse()?;
+            let strategy: Strategy = if strategy == "wrap" { Strategy::Wrap(strategy.span()) } else { Strategy::Variant(strategy) };
+            Ok(Self::Fn { ident, strategy })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// Specifies the overrides passed from [`dispatch_impls()`] to [`dispatch_to_variants()`].
+pub(crate) struct Overrides(Vec<Override>);
+impl Parse for Overrides {
+    #[inline]
+    fn parse(input: ParseStream) -> syn::Result<Self> { Ok(Self(Punctuated::<Override, Token![,]>::parse_terminated(input)?.into_iter().collect())) }
+}
+
+/// Specifies the attributes we're parsing from the `#[dispatch_impls(...)]`-attribute.
+struct DispatchAttributes {
+    /// The path to the trait to implement.
+    path:      Path,
+    /// The items of the trait given explicitly, which are parsed again by
+    /// [`dispatch_to_variants()`].
+    overrides: TokenStream2,
+}
+impl Parse for DispatchAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: Path = input.parse()?;
+        if let Some(seg) = path.segments.iter().find(|seg| !matches!(seg.arguments, PathArguments::None)) {
+            return Err(Error::new(
+                seg.arguments.span(),
+                "Generic arguments are not supported by `#[dispatch_impls(...)]` (the impl is already generic over those of the trait)",
+            ));
+        }
+        let mut overrides = TokenStream2::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            overrides = input.fork().parse()?;
+            input.parse::<Overrides>()?;
+        }
+        Ok(Self { path, overrides })
+    }
+}
+
+/// Describes a variant of the enum to dispatch to.
+struct Variant {
+    /// The name of the variant.
+    ident:  Ident,
+    /// The field of the variant (i.e., `0` if it's tuple-like).
+    member: Member,
+    /// The type of its field.
+    ty:     Type,
+}
+impl Variant {
+    /// Generates a pattern or expression that constructs this variant.
+    ///
+    /// # Arguments
+    /// - `value`: The value of the field.
+    ///
+    /// # Returns
+    /// A [`TokenStream2`] encoding `Self::Variant(value)` or `Self::Variant { field: value }`.
+    fn wrap(&self, value: impl ToTokens) -> TokenStream2 {
+        let Self { ident, member, .. } = self;
+        match member {
+            Member::Named(field) => quote! { Self::#ident { #field: #value } },
+            Member::Unnamed(_) => quote! { Self::#ident(#value) },
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `dispatch_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + a call to the shape of the trait.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn dispatch_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    let DispatchAttributes { path, overrides } = syn::parse2(attr)?;
+    let item: ItemEnum = match syn::parse2(item)? {
+        Item::Enum(item) => item,
+        item => return Err(Error::new(item.span(), "`#[dispatch_impls(...)]` can only be used on enums")),
+    };
+
+    // Call the shape of the trait, which lives next to it
+    let mut stripped: ItemEnum = item.clone();
+    stripped.attrs.clear();
+    Ok(call_shape(&path, overrides, item.into_token_stream(), stripped.into_token_stream()))
+}
+
+/// Implements a trait for an enum by dispatching to its variants.
+///
+/// # Arguments
+/// - `todo`: The [`ImplsToDo`] describing the trait.
+/// - `path`: The path to the trait as given by the user.
+/// - `overrides`: The [`Overrides`] for items that can't be dispatched as-is.
+/// - `item`: The [`ItemEnum`] to implement the trait for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the impl of the trait for the enum.
+///
+/// # Errors
+/// This function may error if the trait cannot be dispatched.
+pub(crate) fn dispatch_to_variants(todo: ImplsToDo, path: Path, overrides: Overrides, item: ItemEnum) -> Result<TokenStream2, Error> {
+    let def = &todo.def;
+    let name: &Ident = &item.ident;
+
+    // Find the types of the variants
+    let mut variants: Vec<Variant> = Vec::with_capacity(item.variants.len());
+    for variant in &item.variants {
+        if variant.fields.len() != 1 {
+            return Err(Error::new(variant.span(), format!("Variant '{}' of enum '{name}' must have exactly one field to dispatch to", variant.ident)));
+        }
+        let field = variant.fields.iter().next().unwrap();
+        variants.push(Variant {
+            ident:  variant.ident.clone(),
+            member: variant.fields.members().next().unwrap(),
+            ty:     field.ty.clone(),
+        });
+    }
+    let Some(first) = variants.first() else {
+        return Err(Error::new(name.span(), format!("Enum '{name}' has no variants to dispatch to")));
+    };
+
+    // Check that the overrides refer to something
+    let mut items: HashMap<String, Override> = HashMap::with_capacity(overrides.0.len());
+    for over in overrides.0 {
+        let ident: &Ident = over.ident();
+        let found: bool = def.items.iter().any(|item| match (item, &over) {
+            (TraitItem::Type(ty), Override::Type { .. }) => &ty.ident == ident,
+            (TraitItem::Const(c), Override::Const { .. }) => &c.ident == ident,
+            (TraitItem::Fn(f), Override::Fn { .. }) => &f.sig.ident == ident,
+            _ => false,
+        });
+        if !found {
+            let kind: &str = match over {
+                Override::Type { .. } => "associated type",
+                Override::Const { .. } => "associated constant",
+                Override::Fn { .. } => "method",
+            };
+            return Err(Error::new(ident.span(), format!("Trait '{}' has no {kind} '{ident}'", def.ident)));
+        }
+        if let Override::Fn { strategy: Strategy::Variant(variant), .. } = &over {
+            if !variants.iter().any(|v| &v.ident == variant) {
+                return Err(Error::new(variant.span(), format!("Enum '{name}' has no variant '{variant}' (or use `wrap`)")));
+            }
+        }
+        items.insert(ident.to_string(), over);
+    }
+
+    // Build the generics of the impl, and the bounds on the variants
+    let trait_args: Vec<TokenStream2> = generic_args(&def.generics);
+    let (_, trait_ty_gen, _) = def.generics.split_for_impl();
+    let mut generics: Generics = def.generics.clone();
+    inject_additional_types(None, def, &Some(item.generics.clone()), false, &mut generics);
+    let (impl_gen, _, _) = generics.split_for_impl();
+    let (_, ty_gen, _) = item.generics.split_for_impl();
+    let mut where_clause: WhereClause = def.generics.where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+    where_clause.predicates.extend(item.generics.where_clause.iter().flat_map(|w| w.predicates.iter().cloned()));
+
+    // Associated types are the same as that of the first variant's, or those given by the user
+    let mut bindings: Vec<(Ident, Type, bool)> = Vec::new();
+    let mut impl_items: Vec<TokenStream2> = Vec::with_capacity(todo.item_mask.count_ones());
+    for (i, trait_item) in def.items.iter().enumerate() {
+        let TraitItem::Type(TraitItemType { attrs, type_token, ident, generics, default, semi_token, .. }) = trait_item else { continue };
+        if !todo.item_mask[i] {
+            continue;
+        }
+        let (_, _, where_clause) = generics.split_for_impl();
+        match items.get(&ident.to_string()) {
+            Some(Override::Type { generics: over, ty, .. }) => {
+                if generics.params.is_empty() {
+                    bindings.push((ident.clone(), ty.clone(), true));
+                }
+                let eq = default.as_ref().map(|(eq, _)| *eq).unwrap_or_default();
+                impl_items.push(quote! { #(#attrs)* #type_token #ident #over #eq #ty #where_clause #semi_token });
+            },
+            _ if !generics.params.is_empty() => {
+                return Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "Cannot dispatch generic associated type '{ident}' because it might differ per variant (give it with `type {ident}<...> = ...` \
+                         in `#[dispatch_impls(...)]`)"
+                    ),
+                ));
+            },
+            _ => {
+                let ty: &Type = &first.ty;
+                let ty: Type = parse_quote! { <#ty as #path #trait_ty_gen>::#ident };
+                let eq = default.as_ref().map(|(eq, _)| *eq).unwrap_or_default();
+                impl_items.push(quote! { #(#attrs)* #type_token #ident #eq #ty #semi_token });
+                bindings.push((ident.clone(), ty, false));
+            },
+        }
+    }
+    let mut seen: Vec<String> = Vec::with_capacity(variants.len());
+    for variant in &variants {
+        let ty: &Type = &variant.ty;
+        let key: String = ty.to_token_stream().to_string();
+        if seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+
+        // The first variant defines the associated types that aren't given, so it doesn't need to
+        // be bound to those
+        let bindings: Vec<TokenStream2> = bindings
+            .iter()
+            .filter(|(_, _, given)| seen.len() > 1 || *given)
+            .map(|(ident, value, _)| quote! { #ident = #value })
+            .collect();
+        let args: Vec<&TokenStream2> = trait_args.iter().chain(bindings.iter()).collect();
+        let bound: WherePredicate = if args.is_empty() { parse_quote! { #ty: #path } } else { parse_quote! { #ty: #path<#(#args),*> } };
+        where_clause.predicates.push(bound);
+    }
+
+    // Then do the constants and methods
+    let this = Ident::new("self", Span::call_site());
+    let inner = Ident::new("inner", Span::mixed_site());
+    for (i, trait_item) in def.items.iter().enumerate() {
+        if !todo.item_mask[i] {
+            continue;
+        }
+        match trait_item {
+            // Constants can't be checked to be the same, so they have to be given
+            TraitItem::Const(TraitItemConst { attrs, const_token, ident, colon_token, ty, default, semi_token, .. }) => {
+                match items.get(&ident.to_string()) {
+                    Some(Override::Const { value, .. }) => impl_items.push(quote! { #(#attrs)* #const_token #ident #colon_token #ty = #value #semi_token }),
+                    _ if default.is_some() => {},
+                    _ => {
+                        return Err(Error::new(
+                            ident.span(),
+                            format!(
+                                "Cannot dispatch associated constant '{ident}' because it might differ per variant (give it with `const {ident} = ...` in \
+                                 `#[dispatch_impls(...)]`)"
+                            ),
+                        ));
+                    },
+                }
+            },
+
+            // Methods are matched on `self`, and then forwarded to the variant
+            TraitItem::Fn(TraitItemFn { attrs, sig, default, .. }) => {
+                let ident: &Ident = &sig.ident;
+                let receiver: Option<ReceiverKind> = todo.receivers.get(&i).copied();
+                let strategy: Option<&Strategy> = match items.get(&ident.to_string()) {
+                    Some(Override::Fn { strategy, .. }) => Some(strategy),
+                    _ => None,
+                };
+                let mentions_self: bool = sig.inputs.iter().any(|arg| matches!(arg, FnArg::Typed(t) if mentions_self(&t.ty)))
+                    || (!returns_self(&sig.output) && matches!(&sig.output, ReturnType::Type(_, ty) if mentions_self(ty)));
+
+                // Decide if we can do this method
+                let reason: Option<String> = match (receiver, strategy) {
+                    (Some(ReceiverKind::Ref | ReceiverKind::Mut | ReceiverKind::Owned), _) | (None, Some(_)) if mentions_self => {
+                        Some("its signature mentions `Self` other than as return type".into())
+                    },
+                    (Some(ReceiverKind::Ref | ReceiverKind::Mut | ReceiverKind::Owned), None) if returns_self(&sig.output) => {
+                        Some(format!("it returns `Self` (give a strategy with `fn {ident} = wrap` in `#[dispatch_impls(...)]`)"))
+                    },
+                    (Some(ReceiverKind::Ref | ReceiverKind::Mut | ReceiverKind::Owned), Some(Strategy::Variant(variant))) => {
+                        return Err(Error::new(variant.span(), format!("Method '{ident}' takes `self`, so it is always dispatched to the matched variant")));
+                    },
+                    (Some(ReceiverKind::Ref | ReceiverKind::Mut | ReceiverKind::Owned), Some(Strategy::Wrap(span))) if !returns_self(&sig.output) => {
+                        return Err(Error::new(*span, format!("Method '{ident}' does not return `Self`, so there's nothing to wrap")));
+                    },
+                    (Some(ReceiverKind::Ref | ReceiverKind::Mut | ReceiverKind::Owned), _) => None,
+                    (Some(receiver), _) => Some(format!("it takes `{receiver}`, which can't be matched on")),
+                    (None, Some(Strategy::Wrap(span))) => {
+                        return Err(Error::new(*span, format!("Method '{ident}' has no `self` to match on, so it can't be wrapped (give a variant instead)")));
+                    },
+                    (None, Some(Strategy::Variant(_))) => None,
+                    (None, None) => Some(format!("it has no `self` to match on (give a variant to use with `fn {ident} = ...` in `#[dispatch_impls(...)]`)")),
+                };
+                if let Some(reason) = reason {
+                    if default.is_some() {
+                        continue;
+                    }
+                    return Err(Error::new(ident.span(), format!("Cannot dispatch method '{ident}' to the variants of '{name}' because {reason}")));
+                }
+
+                // We don't need mutable `self` if we move it
+                let mut sig = sig.clone();
+                strip_owned_mut(&mut sig);
+
+                // Generate the call for every variant we dispatch to
+                let passing_args: Punctuated<Pat, Token![,]> = passing_args(&sig);
+                let call_generics: TokenStream2 = todo.call_generics(i, &sig);
+                let asyncness: Option<TokenStream2> = sig.asyncness.map(|_| quote! { .await });
+                let call = |variant: &Variant, this: Option<&Ident>| -> TokenStream2 {
+                    let ty: &Type = &variant.ty;
+                    let mut args: Vec<TokenStream2> = this.into_iter().map(ToTokens::to_token_stream).collect();
+                    args.extend(passing_args.iter().map(ToTokens::to_token_stream));
+                    let call = quote! { <#ty as #path #trait_ty_gen>::#ident #call_generics (#(#args),*) #asyncness };
+                    if returns_self(&sig.output) { variant.wrap(call) } else { call }
+                };
+                let body: TokenStream2 = match strategy {
+                    Some(Strategy::Variant(ident)) => call(variants.iter().find(|v| &v.ident == ident).unwrap(), None),
+                    _ => {
+                        let arms = variants.iter().map(|variant| {
+                            let pat = variant.wrap(&inner);
+                            let call = call(variant, Some(&inner));
+                            quote! { #pat => #call, }
+                        });
+                        quote! { match #this { #(#arms)* } }
+                    },
+                };
+
+                // Unsafe methods can only be called in an unsafe block
+                let brace_token: Brace = default.as_ref().map(|b| b.brace_token).unwrap_or_default();
+                let mut tokens = quote! { #(#attrs)* #sig };
+                body_to_tokens(&sig, brace_token, TokenStream2::new(), body, &mut tokens);
+                impl_items.push(tokens);
+            },
+
+            // Done before
+            _ => {},
+        }
+    }
+
+    // Now build the overall impl
+    let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+    Ok(quote! { #unsafety impl #impl_gen #path #trait_ty_gen for #name #ty_gen #where_clause { #(#impl_items)* } })
+}
@@ -0,0 +1,304 @@
+//  FN IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:20:32
+//  Last edited:
+//    17 Oct 2026, 10:22:56
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines an attribute macro for automatically implementing traits with
+//!   a single required method for closures.
+//
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, quote};
+use syn::spanned::Spanned as _;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Error, FnArg, GenericParam, Ident, ItemTrait, Lifetime, Pat, PatIdent, Path, ReturnType, Signature, Token, TraitItem, TraitItemFn, Type, TypeImplTrait,
+    TypeReference, WhereClause, WherePredicate, parse_quote,
+};
+
+use crate::pointer_impls::{ImplsToDo, ReceiverKind, free_ident, has_pending_impls, strip_owned_mut};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Finds the first thing in a type that can't be expressed in the arguments or return type of a
+/// closure bound.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+/// - `in_output`: Whether this type is the return type of a method taking `self` by reference,
+///   in which case elided lifetimes refer to `self` and can't be expressed either.
+///
+/// # Returns
+/// The [`Span`] of the offending part and why, or [`None`] if it's fine.
+fn find_unsupported(ty: &Type, in_output: bool) -> Option<(Span, &'static str)> {
+    let mut finder = UnsupportedFinder { in_output, found: None };
+    finder.visit_type_mut(&mut ty.clone());
+    finder.found
+}
+
+
+
+
+
+/***** VISITORS *****/
+/// Visitor that finds types that can't be expressed in closure bounds.
+struct UnsupportedFinder {
+    /// Whether we're checking the return type of a method taking `self` by reference.
+    in_output: bool,
+    /// The first offending part found.
+    found: Option<(Span, &'static str)>,
+}
+impl VisitMut for UnsupportedFinder {
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        if self.found.is_none() && node.segments.first().is_some_and(|seg| seg.ident == "Self") {
+            self.found = Some((node.span(), "it mentions `Self`, which is the closure itself"));
+            return;
+        }
+        visit_mut::visit_path_mut(self, node)
+    }
+
+    fn visit_type_impl_trait_mut(&mut self, node: &mut TypeImplTrait) {
+        if self.found.is_none() {
+            self.found = Some((node.span(), "closures cannot take or return `impl Trait`"));
+        }
+    }
+
+    fn visit_type_reference_mut(&mut self, node: &mut TypeReference) {
+        if self.found.is_none() && self.in_output && node.lifetime.is_none() {
+            self.found = Some((node.and_token.span(), "the lifetime of its return type is elided to that of `self` (name it explicitly instead)"));
+            return;
+        }
+        visit_mut::visit_type_reference_mut(self, node)
+    }
+
+    fn visit_lifetime_mut(&mut self, node: &mut Lifetime) {
+        if self.found.is_none() && self.in_output && node.ident == "_" {
+            self.found = Some((node.span(), "the lifetime of its return type is elided to that of `self` (name it explicitly instead)"));
+        }
+    }
+}
+
+
+
+
+
+/***** GENERATION *****/
+/// Finds the one method of a trait without a default implementation.
+///
+/// # Arguments
+/// - `todo`: The [`ImplsToDo`] describing the trait.
+///
+/// # Returns
+/// The index of that method in the trait.
+///
+/// # Errors
+/// This function errors if the trait has anything else that a closure can't implement.
+fn find_method(todo: &ImplsToDo) -> syn::Result<usize> {
+    let def: &ItemTrait = &todo.def;
+    let name: &Ident = &def.ident;
+    let mut method: Option<usize> = None;
+    for (i, item) in def.items.iter().enumerate() {
+        match item {
+            TraitItem::Type(ty) => {
+                return Err(Error::new(
+                    ty.ident.span(),
+                    format!("Cannot implement trait '{name}' for closures because it has associated type '{}'", ty.ident),
+                ));
+            },
+            TraitItem::Const(c) if c.default.is_none() => {
+                return Err(Error::new(
+                    c.ident.span(),
+                    format!("Cannot implement trait '{name}' for closures because associated constant '{}' has no default", c.ident),
+                ));
+            },
+            TraitItem::Fn(f) if f.default.is_none() => {
+                if let Some(first) = method {
+                    let TraitItem::Fn(first) = &def.items[first] else { unreachable!() };
+                    return Err(Error::new(
+                        f.sig.ident.span(),
+                        format!(
+                            "Cannot implement trait '{name}' for closures because it has more than one method without a default ('{}' and '{}')",
+                            first.sig.ident, f.sig.ident
+                        ),
+                    ));
+                }
+                method = Some(i);
+            },
+            _ => {},
+        }
+    }
+    method.ok_or_else(|| Error::new(name.span(), format!("Cannot implement trait '{name}' for closures because it has no method without a default")))
+}
+
+/// Describes the closure that implements a method.
+pub(crate) struct ClosureSig<'s> {
+    /// The closure trait to require (e.g., `Fn`).
+    fn_trait:  Ident,
+    /// The lifetimes of the method, which become a higher-ranked bound.
+    lifetimes: Vec<&'s Lifetime>,
+    /// The types of the arguments of the method.
+    args:      Vec<&'s Type>,
+    /// What the method returns.
+    output:    &'s ReturnType,
+}
+impl<'s> ClosureSig<'s> {
+    /// Finds the closure that implements a method.
+    ///
+    /// # Arguments
+    /// - `receiver`: How the method takes `self`, if at all.
+    /// - `sig`: The [`Signature`] of the method.
+    ///
+    /// # Returns
+    /// A new ClosureSig describing the closure.
+    ///
+    /// # Errors
+    /// This function errors if the method can't be implemented by a closure.
+    pub(crate) fn new(receiver: Option<ReceiverKind>, sig: &'s Signature) -> syn::Result<Self> {
+        let ident: &Ident = &sig.ident;
+        let fn_trait: Ident = match receiver {
+            Some(ReceiverKind::Ref) => Ident::new("Fn", Span::call_site()),
+            Some(ReceiverKind::Mut) => Ident::new("FnMut", Span::call_site()),
+            Some(ReceiverKind::Owned) => Ident::new("FnOnce", Span::call_site()),
+            Some(kind) => {
+                return Err(Error::new(sig.inputs[0].span(), format!("Cannot implement method '{ident}' for closures because it takes `{kind}`")));
+            },
+            None => return Err(Error::new(ident.span(), format!("Cannot implement method '{ident}' for closures because it has no receiver"))),
+        };
+        if let Some(asyncness) = &sig.asyncness {
+            return Err(Error::new(asyncness.span(), format!("Cannot implement async method '{ident}' for closures")));
+        }
+
+        // Only lifetimes can be given to closures, and that through a higher-ranked bound
+        let mut lifetimes: Vec<&Lifetime> = Vec::new();
+        for param in &sig.generics.params {
+            match param {
+                GenericParam::Lifetime(l) => lifetimes.push(&l.lifetime),
+                GenericParam::Type(t) => {
+                    return Err(Error::new(t.ident.span(), format!("Cannot implement method '{ident}' for closures because closures cannot be generic")));
+                },
+                GenericParam::Const(c) => {
+                    return Err(Error::new(c.ident.span(), format!("Cannot implement method '{ident}' for closures because closures cannot be generic")));
+                },
+            }
+        }
+
+        // Then collect the arguments and return type
+        let mut args: Vec<&Type> = Vec::with_capacity(sig.inputs.len());
+        for arg in sig.inputs.iter().skip(1) {
+            let FnArg::Typed(t) = arg else { unreachable!() };
+            if let Some((span, reason)) = find_unsupported(&t.ty, false) {
+                return Err(Error::new(span, format!("Cannot implement method '{ident}' for closures because {reason}")));
+            }
+            args.push(&t.ty);
+        }
+        if let ReturnType::Type(_, ty) = &sig.output {
+            if let Some((span, reason)) = find_unsupported(ty, receiver != Some(ReceiverKind::Owned)) {
+                return Err(Error::new(span, format!("Cannot implement method '{ident}' for closures because {reason}")));
+            }
+        }
+        Ok(Self { fn_trait, lifetimes, args, output: &sig.output })
+    }
+
+    /// Returns the bound on the closure (e.g., `for<'a> ::core::ops::Fn(&'a str) -> u32`).
+    pub(crate) fn bound(&self) -> TokenStream2 {
+        let Self { fn_trait, lifetimes, args, output } = self;
+        if lifetimes.is_empty() {
+            quote! { ::core::ops::#fn_trait(#(#args),*) #output }
+        } else {
+            quote! { for<#(#lifetimes),*> ::core::ops::#fn_trait(#(#args),*) #output }
+        }
+    }
+
+    /// Returns a function pointer type that satisfies [`ClosureSig::bound()`] (e.g.,
+    /// `for<'a> fn(&'a str) -> u32`).
+    pub(crate) fn pointer(&self) -> TokenStream2 {
+        let Self { fn_trait: _, lifetimes, args, output } = self;
+        if lifetimes.is_empty() { quote! { fn(#(#args),*) #output } } else { quote! { for<#(#lifetimes),*> fn(#(#args),*) #output } }
+    }
+}
+
+/// Names the arguments of a method ourselves, as the trait may not have (e.g., `_: u32`).
+///
+/// # Arguments
+/// - `sig`: The [`Signature`] of the method to rename the arguments of.
+///
+/// # Returns
+/// The new names of the arguments, together with their original patterns.
+pub(crate) fn name_args(sig: &mut Signature) -> Vec<(Ident, Pat)> {
+    let mut args: Vec<(Ident, Pat)> = Vec::with_capacity(sig.inputs.len());
+    for arg in &mut sig.inputs {
+        if let FnArg::Typed(t) = arg {
+            let ident = Ident::new(&format!("arg{}", args.len()), Span::mixed_site());
+            let pat = Pat::Ident(PatIdent { attrs: Vec::new(), by_ref: None, mutability: None, ident: ident.clone(), subpat: None });
+            args.push((ident, std::mem::replace(&mut *t.pat, pat)));
+        }
+    }
+    args
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `fn_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + an implementation for closures.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn fn_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    if !attr.is_empty() {
+        return Err(Error::new(attr.span(), "`#[fn_impls]` takes no arguments"));
+    }
+    let raw: ItemTrait = syn::parse2(item)?;
+    let todo: ImplsToDo = syn::parse2(raw.to_token_stream())?;
+
+    // Write the definition itself. If any of our other macros still has to run, it needs the
+    // `#[pointer_impl(...)]`-attributes.
+    let mut tokens: TokenStream2 = if has_pending_impls(&raw) { raw.to_token_stream() } else { todo.def.to_token_stream() };
+
+    // Find the method to implement and what the closure has to look like
+    let i: usize = find_method(&todo)?;
+    let def: &ItemTrait = &todo.def;
+    let TraitItem::Fn(TraitItemFn { attrs, sig, .. }) = &def.items[i] else { unreachable!() };
+    let bound: TokenStream2 = ClosureSig::new(todo.receivers.get(&i).copied(), sig)?.bound();
+    let mut sig: Signature = sig.clone();
+    let args: Vec<Ident> = name_args(&mut sig).into_iter().map(|(ident, _)| ident).collect();
+    // We don't need mutable `self` if we move it
+    strip_owned_mut(&mut sig);
+
+    // The closure has to implement the supertraits, too
+    let name: &Ident = &def.ident;
+    let (_, trait_ty_gen, _) = def.generics.split_for_impl();
+    let f: Ident = free_ident(def, "F");
+    let mut generics = def.generics.clone();
+    generics.params.push(parse_quote! { #f });
+    let (impl_gen, _, _) = generics.split_for_impl();
+    let mut where_clause: WhereClause = def.generics.where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+    let supertraits = &def.supertraits;
+    let closure: WherePredicate = if supertraits.is_empty() { parse_quote! { #f: #bound } } else { parse_quote! { #f: #supertraits + #bound } };
+    where_clause.predicates.push(closure);
+
+    // Now build the overall impl
+    let this = Ident::new("self", Span::call_site());
+    let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+    tokens.extend(quote! {
+        #unsafety impl #impl_gen #name #trait_ty_gen for #f #where_clause {
+            #(#attrs)*
+            #sig { (#this)(#(#args),*) }
+        }
+    });
+    Ok(tokens)
+}
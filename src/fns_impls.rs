@@ -0,0 +1,360 @@
+//  FNS IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:25:17
+//  Last edited:
+//    17 Oct 2026, 10:31:55
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines an attribute macro for generating a struct that implements a
+//!   trait by calling closures.
+//
+
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use syn::ext::IdentExt as _;
+use syn::spanned::Spanned as _;
+use syn::{Error, GenericParam, Ident, ItemTrait, Pat, Signature, Token, TraitItem, TraitItemFn, WhereClause, parse_quote};
+
+use crate::fn_impls::{ClosureSig, name_args};
+use crate::pointer_impls::{ImplsToDo, ReceiverKind, free_ident, generic_args, has_pending_impls, strip_owned_mut};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Turns the name of a method into CamelCase (e.g., `on_event` becomes `OnEvent`).
+///
+/// # Arguments
+/// - `ident`: The name of the method.
+///
+/// # Returns
+/// The name in CamelCase.
+pub(crate) fn camel_case(ident: &Ident) -> String {
+    let mut name: String = String::new();
+    for word in ident.unraw().to_string().split('_') {
+        let mut chars = word.chars();
+        if let Some(c) = chars.next() {
+            name.extend(c.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name
+}
+
+/// Turns the name of a method into the name of the type of its closure (e.g., `on_event` becomes
+/// `OnEventFn`).
+///
+/// The name is chosen such that it doesn't clash with any name in the trait, nor with the
+/// closures of other methods (e.g., `on_event` and `on__event`).
+///
+/// # Arguments
+/// - `def`: The [`ItemTrait`] that the closure implements a method of.
+/// - `ident`: The name of the method.
+/// - `taken`: The names of the closures of other methods. The new one is added to it.
+///
+/// # Returns
+/// The name of the type parameter of the closure.
+fn closure_ty(def: &ItemTrait, ident: &Ident, taken: &mut HashSet<String>) -> Ident {
+    let mut base: String = format!("{}Fn", camel_case(ident));
+    loop {
+        let ty: Ident = free_ident(def, &base);
+        if taken.insert(ty.to_string()) {
+            return ty;
+        }
+        base = format!("{ty}_");
+    }
+}
+
+
+
+
+
+/***** GENERATION *****/
+/// Describes a method for which the user can give a closure.
+struct Method<'s> {
+    /// The method itself.
+    method:  &'s TraitItemFn,
+    /// How it takes `self`.
+    kind:    ReceiverKind,
+    /// The closure implementing it.
+    closure: ClosureSig<'s>,
+    /// The name of the type parameter of its closure.
+    ty:      Ident,
+}
+impl Method<'_> {
+    /// Generates the implementation of this method, which calls the closure.
+    ///
+    /// # Returns
+    /// A [`TokenStream2`] with the method.
+    fn to_impl(&self) -> TokenStream2 {
+        let TraitItemFn { attrs, sig, default, .. } = self.method;
+        let mut sig: Signature = sig.clone();
+        let (args, pats): (Vec<Ident>, Vec<Pat>) = name_args(&mut sig).into_iter().unzip();
+        let this = Ident::new("self", Span::call_site());
+        let ident: &Ident = &self.method.sig.ident;
+        match default {
+            // Without a default, the closure is always there
+            None => {
+                // We don't need mutable `self` if we move it
+                strip_owned_mut(&mut sig);
+                quote! { #(#attrs)* #sig { (#this.#ident)(#(#args),*) } }
+            },
+
+            // Else, it's only called if the user gave one
+            Some(block) => {
+                // We splice the statements of the default into our own block
+                let stmts = &block.stmts;
+                let f = Ident::new("f", Span::mixed_site());
+                let field = match self.kind {
+                    ReceiverKind::Ref => quote! { &#this.#ident },
+                    ReceiverKind::Mut => quote! { &mut #this.#ident },
+                    _ => quote! { #this.#ident },
+                };
+                quote! {
+                    #(#attrs)*
+                    #sig {
+                        match #field {
+                            ::core::option::Option::Some(#f) => #f(#(#args),*),
+                            ::core::option::Option::None => {
+                                #(let #pats = #args;)*
+                                #(#stmts)*
+                            },
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `fns_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + a struct implementing it with closures.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn fns_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    if !attr.is_empty() {
+        return Err(Error::new(attr.span(), "`#[fns_impls]` takes no arguments"));
+    }
+    let raw: ItemTrait = syn::parse2(item)?;
+    let todo: ImplsToDo = syn::parse2(raw.to_token_stream())?;
+
+    // Find the methods that get a closure. Those that can't are inherited if possible.
+    let def: &ItemTrait = &todo.def;
+    let name: &Ident = &def.ident;
+    let mut methods: Vec<Method> = Vec::new();
+    let mut taken: HashSet<String> = HashSet::new();
+    for (i, item) in def.items.iter().enumerate() {
+        match item {
+            TraitItem::Type(ty) => {
+                return Err(Error::new(
+                    ty.ident.span(),
+                    format!("Cannot implement trait '{name}' with closures because it has associated type '{}'", ty.ident),
+                ));
+            },
+            TraitItem::Const(c) if c.default.is_none() => {
+                return Err(Error::new(
+                    c.ident.span(),
+                    format!("Cannot implement trait '{name}' with closures because associated constant '{}' has no default", c.ident),
+                ));
+            },
+            TraitItem::Fn(f) if f.sig.ident.unraw() == "from_fns" => {
+                return Err(Error::new(
+                    f.sig.ident.span(),
+                    format!("Cannot implement trait '{name}' with closures because method 'from_fns' clashes with the one that starts building it"),
+                ));
+            },
+            TraitItem::Fn(f) => {
+                let kind: Option<ReceiverKind> = todo.receivers.get(&i).copied();
+                match (ClosureSig::new(kind, &f.sig), kind) {
+                    (Ok(_), Some(_)) if f.sig.ident.unraw() == "build" => {
+                        return Err(Error::new(
+                            f.sig.ident.span(),
+                            format!("Cannot implement trait '{name}' with closures because the setter of method 'build' clashes with the builder's `build()`"),
+                        ));
+                    },
+                    (Ok(closure), Some(kind)) => methods.push(Method { method: f, kind, closure, ty: closure_ty(def, &f.sig.ident, &mut taken) }),
+                    (Err(_), _) if f.default.is_some() => {},
+                    (Err(err), _) => return Err(err),
+                    (Ok(_), None) => unreachable!(),
+                }
+            },
+            _ => {},
+        }
+    }
+
+    // The structs are generic over those of the trait (without bounds)...
+    let vis = &def.vis;
+    let fns = format_ident!("{name}Fns");
+    let builder = format_ident!("{name}FnsBuilder");
+    let params: Vec<TokenStream2> = def
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(l) => l.lifetime.to_token_stream(),
+            GenericParam::Type(t) => t.ident.to_token_stream(),
+            GenericParam::Const(c) => {
+                let (ident, ty) = (&c.ident, &c.ty);
+                quote! { const #ident: #ty }
+            },
+        })
+        .collect();
+    let args: Vec<TokenStream2> = generic_args(&def.generics);
+    let markers: Vec<TokenStream2> = def
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(l) => {
+                let lifetime = &l.lifetime;
+                quote! { &#lifetime () }
+            },
+            GenericParam::Type(t) => t.ident.to_token_stream(),
+            GenericParam::Const(c) => {
+                let ident = &c.ident;
+                quote! { [(); #ident] }
+            },
+        })
+        .collect();
+    let marker = Ident::new("_marker", Span::call_site());
+    let phantom = quote! { ::core::marker::PhantomData };
+
+    // ...and of the closures of all the methods
+    let idents: Vec<&Ident> = methods.iter().map(|m| &m.method.sig.ident).collect();
+    let tys: Vec<&Ident> = methods.iter().map(|m| &m.ty).collect();
+    let fields: Vec<TokenStream2> = methods
+        .iter()
+        .map(|m| {
+            let (ident, ty) = (&m.method.sig.ident, &m.ty);
+            if m.method.default.is_some() { quote! { #ident: ::core::option::Option<#ty> } } else { quote! { #ident: #ty } }
+        })
+        .collect();
+    let bounds: Vec<TokenStream2> = methods.iter().map(|m| m.closure.bound()).collect();
+    let unset: Vec<TokenStream2> = methods.iter().map(|m| if m.method.default.is_some() { m.closure.pointer() } else { quote! { () } }).collect();
+    let unset_values: Vec<TokenStream2> =
+        methods.iter().map(|m| if m.method.default.is_some() { quote! { ::core::option::Option::None } } else { quote! { () } }).collect();
+
+    // Write the definition itself with the way to start building added to it. If any of our other
+    // macros still has to run, it needs the `#[pointer_impl(...)]`-attributes; those shouldn't try
+    // to forward the new method, since it doesn't take `self`.
+    let s: Ident = free_ident(def, "S");
+    let pending: bool = has_pending_impls(&raw);
+    let mut out: ItemTrait = if pending { raw.clone() } else { def.clone() };
+    let inherit: Option<TokenStream2> = if pending { Some(quote! { #[pointer_impl(inherit)] }) } else { None };
+    let from_fns_doc: String = format!(" Starts building a [`{fns}`], which has to be given a closure for every method of [`{name}`] without a default.");
+    out.items.push(parse_quote! {
+        #[doc = #from_fns_doc]
+        #inherit
+        #[inline]
+        fn from_fns() -> #builder<#(#args,)* #(#unset,)* Self>
+        where
+            Self: ::core::marker::Sized,
+        {
+            #builder { #(#idents: #unset_values,)* #marker: #phantom }
+        }
+    });
+    let mut tokens: TokenStream2 = out.to_token_stream();
+
+    // Generate the structs. Parts of these go unused if the user doesn't set every closure, so we
+    // don't warn about that. The builder also remembers what it builds, which is how `from_fns()`
+    // knows to build this struct.
+    let fns_doc: String = format!(" Implements [`{name}`] by calling a closure for every method. Build it with [`{name}::from_fns()`].");
+    let builder_doc: String = format!(" Builds a [`{fns}`] by giving it closures one by one.");
+    tokens.extend(quote! {
+        #[doc = #fns_doc]
+        #[allow(dead_code)]
+        #vis struct #fns<#(#params,)* #(#tys),*> {
+            #(#fields,)*
+            #marker: #phantom<fn() -> (#(#markers,)*)>,
+        }
+
+        #[doc = #builder_doc]
+        #[allow(dead_code)]
+        #vis struct #builder<#(#params,)* #(#tys,)* #s> {
+            #(#fields,)*
+            #marker: #phantom<fn() -> (#(#markers,)* #s,)>,
+        }
+    });
+
+    // Then the way to build them
+    let (_, trait_ty_gen, trait_where) = def.generics.split_for_impl();
+    let mut generics = def.generics.clone();
+    generics.params.extend(tys.iter().map(|ty| -> GenericParam { parse_quote! { #ty } }));
+    let (full_impl_gen, _, _) = generics.split_for_impl();
+    let mut setter_generics = generics.clone();
+    setter_generics.params.push(parse_quote! { #s });
+    let (setter_impl_gen, _, _) = setter_generics.split_for_impl();
+    let build_doc: String = format!(" Builds the {fns}.");
+    let mut setters: Vec<TokenStream2> = Vec::with_capacity(methods.len());
+    let f: Ident = free_ident(def, "F");
+    for (m, method) in methods.iter().enumerate() {
+        let (ident, bound) = (idents[m], &bounds[m]);
+        let doc: String = format!(" Sets the closure that implements [`{name}::{}()`].", ident.unraw());
+        let new_tys = tys.iter().enumerate().map(|(i, ty)| if i == m { f.to_token_stream() } else { ty.to_token_stream() });
+        let values = idents.iter().enumerate().map(|(i, other)| {
+            if i != m {
+                quote! { self.#other }
+            } else if method.method.default.is_some() {
+                quote! { ::core::option::Option::Some(#ident) }
+            } else {
+                ident.to_token_stream()
+            }
+        });
+        setters.push(quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #ident<#f>(self, #ident: #f) -> #builder<#(#args,)* #(#new_tys,)* #s>
+            where
+                #f: #bound,
+            {
+                #builder { #(#idents: #values,)* #marker: #phantom }
+            }
+        });
+    }
+    tokens.extend(quote! {
+        #[allow(dead_code)]
+        impl #setter_impl_gen #builder<#(#args,)* #(#tys,)* #s> #trait_where {
+            #(#setters)*
+        }
+
+        #[allow(dead_code)]
+        impl #full_impl_gen #builder<#(#args,)* #(#tys,)* #fns<#(#args,)* #(#tys),*>> #trait_where {
+            #[doc = #build_doc]
+            #[inline]
+            #vis fn build(self) -> #fns<#(#args,)* #(#tys),*>
+            where
+                #(#tys: #bounds,)*
+            {
+                #fns { #(#idents: self.#idents,)* #marker: #phantom }
+            }
+        }
+    });
+
+    // Finally, implement the trait for it
+    let mut where_clause: WhereClause = def.generics.where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+    where_clause.predicates.extend(tys.iter().zip(&bounds).map(|(ty, bound)| -> syn::WherePredicate { parse_quote! { #ty: #bound } }));
+    let items: Vec<TokenStream2> = methods.iter().map(Method::to_impl).collect();
+    let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+    tokens.extend(quote! {
+        #unsafety impl #full_impl_gen #name #trait_ty_gen for #fns<#(#args,)* #(#tys),*> #where_clause {
+            #(#items)*
+        }
+    });
+    Ok(tokens)
+}
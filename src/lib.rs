@@ -4,7 +4,7 @@
 //  Created:
 //    13 Dec 2024, 14:22:24
 //  Last edited:
-//    17 Dec 2024, 16:45:39
+//    17 Oct 2026, 10:34:45
 //  Auto updated?
 //    Yes
 //
@@ -44,11 +44,40 @@
 //!   See the documentation of the `pointer_impl`-attribute macro for the full specification of which
 //!   types are supported and how to use it.
 //!   
+//!   Similarly, newtypes can implement a trait by delegating to one of their fields, and enums by
+//!   dispatching to their variants:
+//!   ```rust
+//!   #[auto_traits::delegate_impls]
+//!   #[auto_traits::pointer_impls]
+//!   trait Foo {
+//!       fn foo(&self) -> &str;
+//!   }
+//!   
+//!   #[auto_traits::delegate(Foo)]
+//!   struct Bar(Box<dyn Foo>);
+//!   
+//!   #[auto_traits::dispatch_impls(Foo)]
+//!   enum Baz {
+//!       Bar(Bar),
+//!       Quz(std::rc::Rc<dyn Foo>),
+//!   }
+//!   ```
+//!   See the documentation of the `delegate_impls`-, `delegate`- and `dispatch_impls`-attribute macros
+//!   for more information.
+//!   
+//!   Finally, the `tuple_impls`- and `collection_impls`-attribute macros implement a trait for tuples and
+//!   standard collections (e.g., `Vec<Box<dyn Foo>>`) of implementors, by calling every element in turn.
+//!   And for traits with a single method, the `fn_impls`-attribute macro implements them for closures,
+//!   while the `fns_impls`-attribute macro generates a struct that implements any trait with closures.
+//!   For tests, the `mock_impls`-attribute macro generates a mock of a trait that records its
+//!   calls and returns queued values.
+//!   
 //!   ## Features
 //!   The crate supports the following features:
-//!   - `parking_lot`: Adds blanket implementations for
+//!   - `parking_lot`: Deprecated, as it doesn't do anything anymore. Instead, use `impl parking_lot` in
+//!     the `pointer_impls` macro to implement traits for
 //!     [`parking_lot`](https://crates.io/crates/parking_lot)'s `MutexGuard`, `RwLockReadGuard` and
-//!     `RwLockWriteGuard` types to every invocation of the `pointer_impls` macro.
+//!     `RwLockWriteGuard` types.
 //!   
 //!   ## Documentation
 //!   You can generate the code documentation by running:
@@ -69,7 +98,14 @@
 //
 
 // Modules
+mod collection_impls;
+mod delegate_impls;
+mod dispatch_impls;
+mod fn_impls;
+mod fns_impls;
+mod mock_impls;
 mod pointer_impls;
+mod tuple_impls;
 
 // Imports
 use proc_macro::TokenStream;
@@ -96,22 +132,227 @@ use proc_macro::TokenStream;
 ///
 /// This will automatically implement a blanket implementation that forwards everything to the
 /// implementation of object `T` for:
-/// - `&T` *
-/// - `&mut T`
-/// - `Box<T>`
-/// - `Rc<T>` *
-/// - `Arc<T>` *
-/// - `Ref` *
-/// - `RefMut`
-/// - `MutexGuard`
-/// - `RwLockReadGuard` *
-/// - `RwLockWriteGuard`
-/// - (`parking_lot` feature) `parking_lot::MutexGuard`
-/// - (`parking_lot` feature) `parking_lot::RwLockReadGuard` *
-/// - (`parking_lot` feature) `parking_lot::RwLockWriteGuard`
+/// - `refs`:
+///     - `&T` *
+///     - `&mut T`
+/// - `smart`:
+///     - `Box<T>`
+///     - `Rc<T>` *
+///     - `Arc<T>` *
+/// - `cells`:
+///     - `Ref` *
+///     - `RefMut`
+/// - `std_locks`:
+///     - `MutexGuard`
+///     - `RwLockReadGuard` *
+///     - `RwLockWriteGuard`
 ///
 /// where types marked with an aterisk (*) are only implemented if the trait has no methods with
-/// `&mut self`.
+/// `&mut self` (except those with a default implementation, which are inherited instead). The
+/// names in front are the groups they belong to, which can be used to (un)implement them together
+/// (see [below](#specifying-types)).
+///
+/// Additionally, the following groups are available but not implemented by default (use, e.g.,
+/// `impl cow` to opt-in):
+/// - `cow`: `Cow<'a, T>` *, requiring `T: ToOwned`.
+/// - `manually_drop`: `ManuallyDrop<T>`, which also forwards methods taking `self`.
+/// - `assert_unwind_safe`: `AssertUnwindSafe<T>`, which also forwards methods taking `self`. Only
+///   implemented for sized `T`.
+/// - `lazy`: `LazyLock<T, F>` * and `LazyCell<T, F>` *. Only implemented for sized `T`.
+/// - `reentrant_lock`: `ReentrantLockGuard<'a, T>` *. Note that this type is unstable, and
+///   requires a nightly compiler with `#![feature(reentrant_lock)]`.
+/// - `pin_box`: `Pin<Box<T>>`, which only implements traits with `&mut self`-methods if
+///   `T: Unpin`.
+/// - `pin`: `Pin<P>` for any pointer `P` whose `P::Target` implements the trait. Traits with
+///   `&mut self`-methods additionally require `P: DerefMut` and `P::Target: Unpin`. This overlaps
+///   with `pin_box`, so only give one of them.
+/// - `parking_lot`: [`parking_lot`](https://crates.io/crates/parking_lot)'s `MutexGuard`,
+///   `RwLockReadGuard` * and `RwLockWriteGuard`.
+/// - `lock_api`: The guards of every [`lock_api`](https://crates.io/crates/lock_api)-based lock,
+///   regardless of the raw lock used: `MutexGuard`, `MappedMutexGuard`, `RwLockReadGuard` *,
+///   `RwLockWriteGuard`, `RwLockUpgradableReadGuard` *, `MappedRwLockReadGuard` *,
+///   `MappedRwLockWriteGuard`, `ReentrantMutexGuard` * and `MappedReentrantMutexGuard` *. Because
+///   `parking_lot`'s guards are `lock_api` guards, this overlaps with `parking_lot`, so only give
+///   one of them.
+/// - `tokio`: The guards of [`tokio`](https://crates.io/crates/tokio)'s `Mutex` and `RwLock`,
+///   both borrowed and owned: `MutexGuard`, `OwnedMutexGuard`, `MappedMutexGuard`,
+///   `OwnedMappedMutexGuard`, `RwLockReadGuard` *, `OwnedRwLockReadGuard` *, `RwLockWriteGuard`,
+///   `OwnedRwLockWriteGuard`, `RwLockMappedWriteGuard` and `OwnedRwLockMappedWriteGuard`. This
+///   requires `tokio`'s `sync` feature.
+/// - `triomphe`: [`triomphe`](https://crates.io/crates/triomphe)'s `Arc` *, `ArcBorrow<'a, T>` *
+///   and `UniqueArc`. `Arc` forwards methods taking `self` like `std`'s `Arc`, and `UniqueArc`
+///   always does.
+/// - `servo_arc`: [`servo_arc`](https://crates.io/crates/servo_arc)'s `Arc` *, `ArcBorrow<'a, T>` *
+///   and `UniqueArc`. Only `Arc` is implemented for unsized `T`.
+/// - `archery`: [`archery`](https://crates.io/crates/archery)'s `SharedPointer<T, K>` *, for any
+///   pointer kind `K`. Only implemented for sized `T`.
+///
+/// The groups for other crates (`parking_lot` and onwards) require your crate to depend on them.
+/// They are found under whatever name you depend on them, so renamed dependencies work too.
+///
+///
+/// ## Unsized types
+/// By default, `T` is given a `?Sized`-bound such that the impls also work for unsized types, like
+/// `Box<dyn Foo>` or `&str`:
+/// ```rust
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls]
+/// trait Foo {
+///     fn foo(&self) -> &str;
+/// }
+///
+/// fn foo(foo: impl Foo) -> String { foo.foo().into() }
+///
+/// struct Bar;
+/// impl Foo for Bar {
+///     fn foo(&self) -> &str { "bar" }
+/// }
+///
+/// assert_eq!(foo(Box::new(Bar) as Box<dyn Foo>), "bar");
+/// ```
+///
+/// Methods with a `where Self: Sized`-bound can't be forwarded to unsized types. If they have a
+/// default implementation, unsized impls simply inherit it; otherwise, `T` is left `Sized`. You
+/// can control this with the following flags:
+/// - `?Sized` forces the `?Sized`-bound, and errors if any method prevents it; and
+/// - `Sized` forces `T` to always be sized.
+/// ```compile_fail
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls(?Sized)]
+/// trait Foo {
+///     fn foo(&self) -> &str
+///     where
+///         Self: Sized;
+/// }
+/// ```
+///
+///
+/// ## Methods taking `self`
+/// Methods that take `self` by value can only be forwarded by pointers that own their contents.
+/// By default, this is only `Box<T>`, which moves out of itself (and hence requires `T: Sized`).
+/// Any other type is skipped for traits with such methods, unless the method has a default
+/// implementation; in that case, it is simply inherited.
+///
+/// Shared pointers (`Rc<T>` and `Arc<T>`) can be included by choosing how to obtain the value:
+/// - `owned = unwrap` uses `try_unwrap()`, and panics if the pointer is still shared; or
+/// - `owned = clone` uses `unwrap_or_clone()`, which requires `T: Clone`.
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls(owned = clone)]
+/// trait IntoFoo {
+///     fn into_foo(self) -> String;
+/// }
+///
+/// #[derive(Clone)]
+/// struct Bar;
+/// impl IntoFoo for Bar {
+///     fn into_foo(self) -> String { "bar".into() }
+/// }
+///
+/// fn into_foo(foo: impl IntoFoo) -> String { foo.into_foo() }
+///
+/// let bar = Rc::new(Bar);
+/// assert_eq!(into_foo(bar.clone()), "bar");
+/// assert_eq!(into_foo(Box::new(Bar)), "bar");
+/// ```
+///
+/// ## Typed receivers
+/// Methods can also take `self` with an explicit type. These are supported as follows:
+/// - `self: Self`, `self: &Self` and `self: &mut Self` behave as their shorthands;
+/// - `self: Box<Self>`, `self: Rc<Self>` and `self: Arc<Self>` are only forwarded by that pointer
+///   itself (e.g., `Box<Box<T>>` becomes `Box<T>`);
+/// - `self: Pin<&Self>` is forwarded by every pointer without a custom expression, and
+///   `self: Pin<&mut Self>` by every mutable one. They require `T: Unpin` to project the pin,
+///   except for `Pin`s themselves (see the `pin` group), which project it to their pointee.
+///
+/// Like before, types that can't forward a method are skipped, unless the method has a default
+/// implementation. If you explicitly asked for a type with `impl`, this is an error explaining why
+/// it can't be implemented.
+/// ```rust
+/// use std::pin::Pin;
+/// use std::task::Poll;
+///
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls]
+/// trait Foo {
+///     fn poll_foo(self: Pin<&mut Self>) -> Poll<String>;
+/// }
+///
+/// struct Bar;
+/// impl Foo for Bar {
+///     fn poll_foo(self: Pin<&mut Self>) -> Poll<String> { Poll::Ready("bar".into()) }
+/// }
+///
+/// fn poll_foo(mut foo: impl Foo + Unpin) -> Poll<String> { Pin::new(&mut foo).poll_foo() }
+///
+/// assert_eq!(poll_foo(Box::new(Bar)), Poll::Ready("bar".into()));
+/// assert_eq!(poll_foo(&mut Bar), Poll::Ready("bar".into()));
+/// ```
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// use auto_traits::pointer_impls;
+///
+/// // `Rc<T>` can't move out of itself without `owned = ...`
+/// #[pointer_impls(impl Rc<T>)]
+/// trait Foo {
+///     fn into_foo(self: Box<Self>) -> String;
+/// }
+/// ```
+///
+/// ## Async methods
+/// `async fn`s are forwarded by returning the future of `T` as-is (i.e., as
+/// `fn foo(&self) -> impl Future<Output = ...>`). As such, the future of a pointer is `Send` if the
+/// future of `T` is. Methods returning `impl Trait` are forwarded as usual.
+/// ```rust
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls]
+/// trait Foo {
+///     async fn foo(&self) -> String;
+///     fn chars(&self) -> impl Iterator<Item = char> + '_;
+/// }
+///
+/// struct Bar;
+/// impl Foo for Bar {
+///     async fn foo(&self) -> String { "bar".into() }
+///     fn chars(&self) -> impl Iterator<Item = char> + '_ { "bar".chars() }
+/// }
+///
+/// fn assert_send<F: Send>(fut: F) -> F { fut }
+///
+/// let bar = std::sync::Arc::new(Bar);
+/// let _fut = assert_send(bar.foo());
+/// assert_eq!(bar.chars().collect::<String>(), "bar");
+/// ```
+///
+/// ## Unsafe traits
+/// For `unsafe trait`s, the generated impls are `unsafe impl`s. Since they simply forward to `T`,
+/// they uphold the same guarantees as `T`'s implementation. Likewise, `unsafe fn`s are forwarded
+/// by calling `T`'s method in an `unsafe`-block, relying on the caller to uphold its contract.
+/// Only that call is in the block, so custom accessors (see below) still can't do unsafe
+/// operations.
+/// ```rust
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls]
+/// unsafe trait Foo {
+///     unsafe fn foo(&self) -> &str;
+/// }
+///
+/// struct Bar;
+/// unsafe impl Foo for Bar {
+///     unsafe fn foo(&self) -> &str { "bar" }
+/// }
+///
+/// assert_eq!(unsafe { Box::new(Bar).foo() }, "bar");
+/// ```
 ///
 ///
 /// ## Changing generics
@@ -145,7 +386,7 @@ use proc_macro::TokenStream;
 /// You can tweak the attribute to change for which types your trait is implemented.
 ///
 /// You can call it like:
-/// ```rust
+/// ```ignore
 /// use auto_traits::pointer_impls;
 ///
 /// #[pointer_impls(...)]
@@ -155,21 +396,59 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 /// where `...` is a comma-separated list of:
-/// - `impl[<T1, T2, ...>] [mut] Foo<_>`, which adds an implementation for the given type.
+/// - `impl[<T1, T2, ...>] [mut] [?Sized] Foo<_> [= ... | { ... }] [where ...]`, which adds an implementation for the given type.
 ///     - Any generics given are generics _added_ to the implementation that aren't already in the
 ///       trait definition. This is usually used for lifetimes.
 ///     - Specifying `mut` indicates that your type supports interior mutability. This is only
 ///       relevant if the trait has methods with mutable access to `self`.
+///     - Specifying `?Sized` indicates that your type can wrap unsized types (e.g.,
+///       `struct Foo<T: ?Sized>`). Without it, `T` will always be sized for this type.
 ///     - By default, the implementation will rely on a [`Deref`]/[`DerefMut`]-implementation to
 ///       coerce the pointers to `self`; however, you can specify an expression over `self` after
 ///       an equals sign to change how this is accessed (e.g., `impl Foo<_> = &self.0`).
+///     - If the trait has methods with different receivers, you can instead give an expression
+///       per receiver in curly brackets (e.g.,
+///       `impl Foo<_> { ref = &self.0, mut = &mut self.0, owned = self.0 }`). Any of them may be
+///       omitted. Giving `mut` implies the type is `mut`, and giving `owned` allows methods taking
+///       `self` to be forwarded.
 ///     - You can use `_` to refer to the original object (e.g., `&_`).
+///     - Any `where`-clause given is added to the implementation for this type only (e.g.,
+///       `impl<'a> Cow<'a, _> where _: ToOwned`).
+/// - `where T: Foo, ...`, which adds the given bounds to ALL implementations. As before, you can
+///   also use `_` to refer to the original object.
 /// - `unimpl Foo<_>`, which removes generating an implementation for a certain type. This is
 ///   mostly useful for excluding types that are defaultly generated.
-///     - Note that types are referred to by absolute path, e.g., `Box` should be
-///       `::std::boxed::Box<_>`.
+///     - Types are matched by what they refer to, not how they are written. For example,
+///       `Box<_>`, `std::boxed::Box<_>` and `::alloc::boxed::Box<_>` all refer to the same type.
+///       Lifetimes are ignored, so `Ref<_>` matches `::std::cell::Ref<'a, _>`.
+///     - It is an error if no type is removed. The error will mention the closest match.
 ///     - Use `*` instead of a typename to remove ALL currently marked-for-implementation types.
 ///       This is useful for when you only want to implement your own types.
+/// - `impl group` or `unimpl group`, which adds or removes all types in one of the groups listed
+///   above (e.g., `refs` or `smart`). Likewise, `impl *` adds all default types. As with single
+///   types, `unimpl` is an error if it removes nothing (e.g., for a group that was never added).
+///
+/// For example, to only implement a trait for references and smart pointers:
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls(unimpl *, impl refs, impl smart)]
+/// trait Foo {
+///     fn foo(&self) -> &str;
+/// }
+///
+/// struct Bar;
+/// impl Foo for Bar {
+///     fn foo(&self) -> &str { "bar" }
+/// }
+///
+/// fn foo(foo: impl Foo) -> String { foo.foo().into() }
+///
+/// assert_eq!(foo(&Bar), "bar");
+/// assert_eq!(foo(Rc::new(Bar)), "bar");
+/// ```
 ///
 /// For examples on how to use these patterns, see the
 /// [`examples/`](https://github.com/Lut99/auto-traits-rs/tree/main/examples) in the repository.
@@ -188,7 +467,101 @@ use proc_macro::TokenStream;
 ///     #[include_impl]
 ///     fn bar(&self) -> &str { "bar" }
 /// }
+///
+/// struct Baz;
+/// impl Foo for Baz {
+///     fn foo(&self) -> &str { "baz" }
+///     fn bar(&self) -> &str { "overridden" }
+/// }
+///
+/// // The override is now also used through pointers
+/// assert_eq!(Foo::bar(&Baz), "overridden");
+/// assert_eq!(Foo::bar(&Box::new(Baz)), "overridden");
+/// ```
+///
+/// You can also flip the default for the whole trait with `defaults = forward`, and then opt-out
+/// per method using `#[pointer_impl(inherit)]` (or, conversely, opt-in with
+/// `#[pointer_impl(forward)]`, which is the same as `#[include_impl]`):
+/// ```rust
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls(defaults = forward)]
+/// trait Foo {
+///     fn foo(&self) -> &str { "foo" }
+///     #[pointer_impl(inherit)]
+///     fn bar(&self) -> &str { "bar" }
+/// }
+///
+/// struct Baz;
+/// impl Foo for Baz {
+///     fn foo(&self) -> &str { "baz" }
+///     fn bar(&self) -> &str { "baz" }
+/// }
+///
+/// assert_eq!(Foo::foo(&Box::new(Baz)), "baz");
+/// assert_eq!(Foo::bar(&Box::new(Baz)), "bar");
+/// ```
+/// Note that associated constants and types with defaults are always forwarded, unless marked
+/// with `#[pointer_impl(inherit)]`.
+///
+/// ## Associated constants
+/// Associated constants are forwarded to `T` like any other item. If you want a pointer to report
+/// a different value instead, use `#[pointer_impl(value = ...)]`. Optionally, you can give a list
+/// of types for which the value holds (using the same notation as `unimpl`):
+/// ```rust
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls]
+/// trait Foo {
+///     const NAME: &'static str;
+///     #[pointer_impl(value(::std::rc::Rc<_>, ::std::sync::Arc<_>) = true)]
+///     const IS_SHARED: bool = false;
+/// }
+///
+/// struct Bar;
+/// impl Foo for Bar {
+///     const NAME: &'static str = "Bar";
+/// }
+///
+/// assert_eq!(<Box<Bar> as Foo>::NAME, "Bar");
+/// assert!(!<Box<Bar> as Foo>::IS_SHARED);
+/// assert!(<std::rc::Rc<Bar> as Foo>::IS_SHARED);
 /// ```
+/// Constants with a type mentioning `Self` (other than through its associated types, e.g.,
+/// `Self::Bar`) can't be forwarded. In the impl, `Self` is the pointer, so `T`'s value has a
+/// different type (e.g., `T`'s `const DEFAULT: Option<Self>` is an `Option<T>`, while
+/// `Box<T>`'s must be an `Option<Box<T>>`). Replacing `Self` by `T` doesn't help either, since
+/// the type of the constant has to match the trait's. Such constants are thus inherited if they
+/// have a default, or else need a value with `#[pointer_impl(value = ...)]` (which is an error
+/// to leave out, as is asking to forward them with `#[pointer_impl(forward)]`).
+///
+/// ## `no_std`
+/// By default, the types are referred to through `::std`. In `#![no_std]` crates, give `core` to
+/// only implement for types in `::core` (i.e., references and cells), or `alloc` to also
+/// implement for those in `::alloc` (e.g., `Box`, `Rc` and `Arc`). The latter requires
+/// `extern crate alloc;` in your crate root. Types that are only in `std` (like the lock guards)
+/// are left out:
+/// ```rust
+/// extern crate alloc;
+///
+/// use auto_traits::pointer_impls;
+///
+/// #[pointer_impls(alloc)]
+/// trait Foo {
+///     fn foo(&self) -> &str;
+/// }
+///
+/// struct Bar;
+/// impl Foo for Bar {
+///     fn foo(&self) -> &str { "bar" }
+/// }
+///
+/// fn foo(foo: impl Foo) -> String { foo.foo().into() }
+///
+/// assert_eq!(foo(alloc::boxed::Box::new(Bar)), "bar");
+/// ```
+/// The generated code never relies on the prelude, so it also works in modules with
+/// `#![no_implicit_prelude]`.
 ///
 ///
 /// # Considerations
@@ -205,3 +578,552 @@ pub fn pointer_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
         Err(err) => err.into_compile_error().into(),
     }
 }
+
+
+/// A procedural macro that allows a trait to be implemented for newtypes with
+/// [`delegate`](macro@delegate).
+///
+/// Attribute macros on a struct cannot see the items of a trait defined elsewhere. Hence, this
+/// attribute generates a hidden `macro_rules!`-macro next to the trait that describes it, which
+/// `#[delegate(...)]` calls to generate the impl. It can be combined with
+/// [`pointer_impls`](macro@pointer_impls), in which case any `#[pointer_impl(...)]`-attributes on
+/// the trait's items are respected by both.
+///
+/// # Usage
+/// Simply add it to any trait definition:
+/// ```rust
+/// use auto_traits::{delegate, delegate_impls};
+///
+/// #[delegate_impls]
+/// trait Foo {
+///     type Bar;
+///     const BAZ: usize;
+///     fn foo(&self) -> &str;
+///     fn quz(&mut self) -> Self::Bar;
+/// }
+///
+/// struct Qux;
+/// impl Foo for Qux {
+///     type Bar = u32;
+///     const BAZ: usize = 42;
+///     fn foo(&self) -> &str { "qux" }
+///     fn quz(&mut self) -> u32 { 42 }
+/// }
+///
+/// #[delegate(Foo)]
+/// struct Newtype(Qux);
+///
+/// assert_eq!(Newtype(Qux).foo(), "qux");
+/// assert_eq!(Newtype(Qux).quz(), 42);
+/// assert_eq!(<Newtype as Foo>::BAZ, 42);
+/// ```
+///
+/// The description is available wherever the trait is defined (and any child modules), but only
+/// in the current crate. Like with [`pointer_impls`](macro@pointer_impls), methods with a default
+/// implementation are inherited.
+#[proc_macro_attribute]
+pub fn delegate_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match delegate_impls::delegate_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that implements a trait for a struct by delegating to one of its fields.
+///
+/// The trait needs to be annotated with [`delegate_impls`](macro@delegate_impls) for this to work.
+///
+/// # Usage
+/// Give the path to the trait, and, if the struct has more than one field, the field to delegate to
+/// with `to = self.<field>`:
+/// ```rust
+/// mod traits {
+///     #[auto_traits::delegate_impls]
+///     pub trait Foo {
+///         fn foo(&self) -> &str;
+///         fn into_foo(self) -> String;
+///     }
+/// }
+///
+/// impl traits::Foo for String {
+///     fn foo(&self) -> &str { self }
+///     fn into_foo(self) -> String { self }
+/// }
+///
+/// #[auto_traits::delegate(traits::Foo, to = self.name)]
+/// struct Person<'a> {
+///     name: String,
+///     nickname: &'a str,
+/// }
+///
+/// use traits::Foo as _;
+/// let person = Person { name: "Amy".into(), nickname: "A" };
+/// assert_eq!(person.nickname, "A");
+/// assert_eq!(person.foo(), "Amy");
+/// assert_eq!(person.into_foo(), "Amy");
+/// ```
+///
+/// The impl is generic over the struct's generics and the trait's generics, and requires the field
+/// to implement the trait. Its associated types and constants are those of the field.
+///
+/// The trait has to be referred to by a path through the module that defines it (or the
+/// module itself), since that's where its description lives. For example, after
+/// `use traits::Foo;`, `#[delegate(Foo)]` only works if the description is imported too (i.e.,
+/// `use traits::{Foo, __delegate_Foo};`).
+///
+/// Methods whose signature mentions `Self` (e.g., constructors returning `Self`) and methods taking
+/// `self` through a pointer (e.g., `self: Box<Self>`) cannot be delegated. These are inherited if
+/// they have a default implementation, or otherwise give an error.
+#[proc_macro_attribute]
+pub fn delegate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match delegate_impls::delegate(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that implements a trait for an enum by dispatching to its variants.
+///
+/// Every variant must have exactly one field, which implements the trait. Then, methods are
+/// implemented by matching on `self` and forwarding to the field of the matched variant. This
+/// gives static dispatch without needing a `Box<dyn Trait>`.
+///
+/// Like for [`delegate`](macro@delegate), the trait needs to be annotated with
+/// [`delegate_impls`](macro@delegate_impls), and has to be referred to by a path through the
+/// module that defines it.
+///
+/// # Usage
+/// Give the path to the trait, followed by anything that can't be dispatched automatically:
+/// ```rust
+/// use auto_traits::{delegate_impls, dispatch_impls};
+///
+/// #[delegate_impls]
+/// trait Foo {
+///     type Bar;
+///     const BAZ: usize;
+///     fn foo(&self) -> Self::Bar;
+///     fn double(&self) -> Self where Self: Sized;
+///     fn new() -> Self where Self: Sized;
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Quz(u32);
+/// impl Foo for Quz {
+///     type Bar = u32;
+///     const BAZ: usize = 1;
+///     fn foo(&self) -> u32 { self.0 }
+///     fn double(&self) -> Self { Self(2 * self.0) }
+///     fn new() -> Self { Self(1) }
+/// }
+/// impl Foo for u32 {
+///     type Bar = u32;
+///     const BAZ: usize = 2;
+///     fn foo(&self) -> u32 { *self }
+///     fn double(&self) -> Self { 2 * self }
+///     fn new() -> Self { 2 }
+/// }
+///
+/// #[dispatch_impls(Foo, const BAZ = 3, fn double = wrap, fn new = Quz)]
+/// #[derive(Debug, PartialEq)]
+/// enum Qux {
+///     Quz(Quz),
+///     Num { num: u32 },
+/// }
+///
+/// assert_eq!(Qux::Quz(Quz(42)).foo(), 42);
+/// assert_eq!(Qux::Num { num: 42 }.foo(), 42);
+/// assert_eq!(Qux::Num { num: 42 }.double(), Qux::Num { num: 84 });
+/// assert_eq!(Qux::new(), Qux::Quz(Quz(1)));
+/// assert_eq!(<Qux as Foo>::BAZ, 3);
+/// ```
+///
+/// In particular:
+/// - Associated types are taken from the first variant, and required to be the same for all
+///   others. Alternatively, give them explicitly with `type Bar = ...` (which is required for
+///   generic associated types).
+/// - Associated constants can't be compared, and are thus inherited if they have a default or
+///   otherwise given explicitly with `const BAZ = ...`.
+/// - Methods returning `Self` need a strategy: `fn double = wrap` wraps the value returned by the
+///   matched variant in that same variant.
+/// - Methods without `self` need a variant to dispatch to instead (e.g., `fn new = Quz`). If they
+///   return `Self`, the value is wrapped in that variant.
+///
+/// Methods that mention `Self` in any other way, or that take `self` through a pointer (e.g.,
+/// `self: Box<Self>`), can't be dispatched. Like with [`pointer_impls`](macro@pointer_impls),
+/// these are inherited if they have a default implementation, as are other methods with a default
+/// implementation (unless marked with `#[pointer_impl(forward)]`).
+#[proc_macro_attribute]
+pub fn dispatch_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match dispatch_impls::dispatch_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that implements a trait for tuples of implementors.
+///
+/// Every tuple up to a maximum arity gets an impl, which calls the method on each of its elements
+/// in order. What to do with the values they return is chosen per method.
+///
+/// # Usage
+/// Annotate the trait, optionally with the largest tuple to implement for (`12` by default):
+/// ```rust
+/// use auto_traits::tuple_impls;
+///
+/// #[tuple_impls(max = 4)]
+/// trait Foo {
+///     #[tuple_impl(type = u32)]
+///     type Bar;
+///     #[tuple_impl(reduce = sum)]
+///     const BAZ: usize;
+///     fn foo(&mut self, bar: Self::Bar);
+///     #[tuple_impl(reduce = and)]
+///     fn is_foo(&self) -> bool;
+///     #[tuple_impl(reduce = collect)]
+///     fn bars(&self) -> Vec<Self::Bar>;
+///     fn new() -> Self where Self: Sized;
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Quz(u32);
+/// impl Foo for Quz {
+///     type Bar = u32;
+///     const BAZ: usize = 1;
+///     fn foo(&mut self, bar: u32) { self.0 += bar; }
+///     fn is_foo(&self) -> bool { self.0 > 0 }
+///     fn bars(&self) -> Vec<u32> { vec![self.0] }
+///     fn new() -> Self { Self(0) }
+/// }
+///
+/// let mut quzs = <(Quz, Quz)>::new();
+/// assert!(!quzs.is_foo());
+/// quzs.foo(42);
+/// assert!(quzs.is_foo());
+/// assert_eq!(quzs.bars(), [42, 42]);
+/// assert_eq!(<(Quz, Quz, Quz) as Foo>::BAZ, 3);
+/// ```
+///
+/// In particular:
+/// - Methods returning `()` call every element in order.
+/// - Methods returning `Self` build a tuple of what every element returns.
+/// - Methods returning anything else need `#[tuple_impl(reduce = ...)]`, which is one of:
+///   - `and` or `or`, which combine the results with `&&` or `||` (and thus short-circuit);
+///   - `sum`, which adds the results with `+`;
+///   - `first`, which returns the result of the first element (but still calls the others); or
+///   - `collect`, which collects the items of all results into a new one of the same type.
+/// - Associated types have to be given with `#[tuple_impl(type = ...)]`, and are required to be
+///   the same for all elements. Generic associated types aren't supported.
+/// - Associated constants are given with `#[tuple_impl(value = ...)]`, or combined with
+///   `#[tuple_impl(reduce = ...)]` (except `collect`).
+///
+/// Arguments taken by value are cloned for all but the last element, and thus need to implement
+/// [`Clone`]. Methods that mention `Self` in any other way, or that take `self` through a pointer
+/// (e.g., `self: Box<Self>`), can't be implemented. Like with
+/// [`pointer_impls`](macro@pointer_impls), these are inherited if they have a default
+/// implementation, and other items without a way to combine them are too.
+#[proc_macro_attribute]
+pub fn tuple_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match tuple_impls::tuple_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that implements a trait for standard collections of implementors.
+///
+/// Methods are implemented by calling them on every element of the collection in order, and what
+/// to do with the values they return is chosen per method. By default, the trait is implemented
+/// for `Vec<T>`, `VecDeque<T>`, `[T]` and `[T; N]`.
+///
+/// # Usage
+/// Annotate the trait:
+/// ```rust
+/// use auto_traits::{collection_impls, pointer_impls};
+///
+/// #[collection_impls]
+/// #[pointer_impls]
+/// trait Foo {
+///     type Bar;
+///     fn foo(&mut self, bar: &Self::Bar);
+///     #[collection_impl(reduce = and)]
+///     fn is_foo(&self) -> bool;
+///     #[collection_impl(reduce = sum)]
+///     fn size(&self) -> usize;
+/// }
+///
+/// struct Quz(Vec<u32>);
+/// impl Foo for Quz {
+///     type Bar = u32;
+///     fn foo(&mut self, bar: &u32) { self.0.push(*bar); }
+///     fn is_foo(&self) -> bool { !self.0.is_empty() }
+///     fn size(&self) -> usize { self.0.len() }
+/// }
+///
+/// // Combines with `#[pointer_impls]` to implement for collections of trait objects
+/// let mut quzs: Vec<Box<dyn Foo<Bar = u32>>> = vec![Box::new(Quz(vec![])), Box::new(Quz(vec![]))];
+/// assert!(!quzs.is_foo());
+/// quzs.foo(&42);
+/// assert!(quzs.is_foo());
+/// assert_eq!(quzs.size(), 2);
+/// assert_eq!([Quz(vec![1, 2]), Quz(vec![3])].size(), 3);
+/// ```
+///
+/// In particular:
+/// - Methods returning `()` call every element in order.
+/// - Methods returning anything else need `#[collection_impl(reduce = ...)]`, which is one of:
+///   - `and` or `or`, which combine the results with `&&` or `||` (and thus short-circuit, and
+///     return `true` or `false` for empty collections, respectively);
+///   - `sum`, which adds the results with `+` (starting from the sum of nothing); or
+///   - `collect`, which collects the items of all results into a new one of the same type.
+/// - Associated types are taken from the elements, unless given with
+///   `#[collection_impl(type = ...)]`.
+/// - Associated constants are taken from the elements, unless given with
+///   `#[collection_impl(value = ...)]`.
+///
+/// Arguments taken by value are cloned for every element, and thus need to implement [`Clone`].
+/// Methods without `self`, taking `self` by value or through a pointer (e.g., `self: Box<Self>`), or
+/// that mention `Self` in their signature can't be implemented. Like with
+/// [`pointer_impls`](macro@pointer_impls), these are inherited if they have a default
+/// implementation, and an error is given otherwise.
+///
+/// ## Choosing collections
+/// To only implement for some of the collections, give them by name: `vec`, `vec_deque`, `slice`
+/// or `array` (e.g., `#[collection_impls(vec, slice)]`). Like for
+/// [`pointer_impls`](macro@pointer_impls), give `core` to only implement for slices and arrays in
+/// `#![no_std]` crates, or `alloc` to refer to `Vec` and `VecDeque` through `::alloc`.
+#[proc_macro_attribute]
+pub fn collection_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match collection_impls::collection_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that implements a trait with a single required method for closures.
+///
+/// The closure takes the arguments of the method and returns what it returns. Which closure trait
+/// is required depends on how the method takes `self`: `&self` requires [`Fn`], `&mut self`
+/// requires [`FnMut`], and `self` requires [`FnOnce`].
+///
+/// # Usage
+/// Annotate the trait:
+/// ```rust
+/// use auto_traits::fn_impls;
+///
+/// struct Request(String);
+/// struct Response(usize);
+///
+/// #[fn_impls]
+/// trait Handler {
+///     fn handle(&self, req: &Request) -> Response;
+///     // Methods with a default implementation are inherited
+///     fn name(&self) -> &'static str { "handler" }
+/// }
+///
+/// // Implemented as `impl<F> Handler for F where F: Fn(&Request) -> Response`
+/// fn serve(handler: &impl Handler) -> usize { handler.handle(&Request("foo".into())).0 }
+///
+/// let handler = |req: &Request| Response(req.0.len());
+/// assert_eq!(serve(&handler), 3);
+/// assert_eq!(handler.name(), "handler");
+/// ```
+///
+/// Lifetimes of the method become a higher-ranked bound on the closure (e.g.,
+/// `fn pick<'a>(&self, a: &'a str, b: &str) -> &'a str` requires
+/// `F: for<'a> Fn(&'a str, &str) -> &'a str`). Supertraits of the trait are required of the
+/// closure, too.
+///
+/// The trait is rejected if it has associated types, associated constants without a default, or
+/// more or less than one method without a default. That method is rejected if:
+/// - it has no `self` or takes it through a pointer (e.g., `self: Box<Self>`);
+/// - it is `async`;
+/// - it has type- or const generics, since closures can't be generic;
+/// - it mentions `Self` or `impl Trait`; or
+/// - it returns a borrow from `&self` (e.g., `fn foo(&self) -> &str`), which closures can't
+///   express. Name the lifetime of the borrow explicitly instead.
+///
+/// Note that this can't be combined with [`pointer_impls`](macro@pointer_impls), since references
+/// and `Box`es of closures are closures themselves and would thus implement the trait twice.
+#[proc_macro_attribute]
+pub fn fn_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match fn_impls::fn_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that generates a struct implementing a trait by calling closures.
+///
+/// For a trait `Foo`, this generates a `FooFns` struct with a closure for every method, which is
+/// built with a `FooFnsBuilder` started by `Foo::from_fns()`. This is useful for tests or quick
+/// adapters, where writing a dedicated type is overkill.
+///
+/// # Usage
+/// Annotate the trait, and then build the struct by giving a closure for every method:
+/// ```rust
+/// use auto_traits::fns_impls;
+///
+/// #[fns_impls]
+/// trait Foo {
+///     fn foo(&self, x: u32) -> u32;
+///     fn bar(&mut self) -> String;
+///     // Methods with a default implementation keep it, unless given a closure
+///     fn baz(&self) -> u32 { self.foo(1) }
+/// }
+///
+/// let mut calls: usize = 0;
+/// let mut foo = Foo::from_fns().foo(|x| 2 * x).bar(|| { calls += 1; calls.to_string() }).build();
+/// assert_eq!(foo.foo(21), 42);
+/// assert_eq!(foo.bar(), "1");
+/// assert_eq!(foo.baz(), 2);
+///
+/// let foo = Foo::from_fns().foo(|x| x).bar(String::new).baz(|| 42).build();
+/// assert_eq!(foo.baz(), 42);
+/// ```
+///
+/// In particular:
+/// - The builder has a method for every method of the trait, which takes a closure with the same
+///   arguments and return type (minus `self`).
+/// - Like for [`fn_impls`](macro@fn_impls), methods taking `&self` are given an [`Fn`],
+///   `&mut self` an [`FnMut`] and `self` an [`FnOnce`]. Lifetimes of the method become a
+///   higher-ranked bound on the closure.
+/// - `from_fns()` is added to the trait as a provided method with a `Self: Sized` bound, so it
+///   doesn't affect dyn-compatibility.
+/// - `build()` is only available once every method without a default has a closure.
+/// - The structs have the same visibility as the trait, and are generic over its generics and the
+///   types of the closures.
+///
+/// Traits with associated types, or associated constants without a default, are rejected. Methods
+/// that can't be given a closure (see [`fn_impls`](macro@fn_impls)) are rejected too, unless they
+/// have a default implementation, which is then always used. Finally, note that any supertraits of
+/// the trait have to be implemented for `FooFns` manually.
+///
+/// Because the builder has its own `build()` and the trait gets `from_fns()`, methods with those
+/// names are rejected as well:
+/// ```compile_fail
+/// use auto_traits::fns_impls;
+///
+/// #[fns_impls]
+/// trait Foo {
+///     fn build(&self) -> u32;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn fns_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match fns_impls::fns_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// A procedural macro that generates a mock of a trait, which records its calls and returns
+/// queued values.
+///
+/// For a trait `Foo`, this generates a `MockFoo` struct implementing it, and a `FooCall` enum with a
+/// variant for every mocked method. Every call to the mock is recorded with a copy of its
+/// arguments, and returns the next value queued for the method with
+/// `mock.expect_foo().returning(...)`.
+///
+/// # Usage
+/// Annotate the trait, optionally with traits to derive for the enum of calls:
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use auto_traits::{mock_impls, pointer_impls};
+///
+/// #[mock_impls(derive(Debug, PartialEq))]
+/// #[pointer_impls]
+/// trait Store {
+///     type Key;
+///
+///     fn get(&self, key: &Self::Key) -> Option<String>;
+///     fn put(&self, key: Self::Key, value: &str);
+///     // Methods with a default implementation are inherited
+///     fn has(&self, key: &Self::Key) -> bool { self.get(key).is_some() }
+/// }
+///
+/// // The code under test can take the mock by reference, or in an `Arc`
+/// fn cache(store: impl Store<Key = u32>, key: u32) -> String {
+///     store.get(&key).unwrap_or_else(|| {
+///         store.put(key, "new");
+///         "new".into()
+///     })
+/// }
+///
+/// let mock: Arc<MockStore<u32>> = Arc::new(MockStore::new());
+/// mock.expect_get().returning(Some("old".into())).returning(None);
+/// assert_eq!(cache(&*mock, 1), "old");
+/// assert_eq!(cache(mock.clone(), 2), "new");
+/// assert_eq!(*mock.calls(), [
+///     StoreCall::Get { key: 1 },
+///     StoreCall::Get { key: 2 },
+///     StoreCall::Put { key: 2, value: "new".into() },
+/// ]);
+/// ```
+///
+/// In particular:
+/// - The mock is generic over the type- and const parameters of the trait, followed by its
+///   associated types (e.g., `MockStore<Key>`). Its lifetime parameters are left to the impl.
+/// - `calls()` returns the calls made so far, in order. Their arguments are stored by cloning them,
+///   or, for references, by cloning what they point to (e.g., `&str` as a `String` and `&[T]` as a
+///   `Vec<T>`). Arguments that can't be stored, e.g., because they borrow for a lifetime of the
+///   method or have its type parameters, are formatted with [`Debug`](core::fmt::Debug) instead.
+///   This can be forced for all arguments of a method with `#[mock_impl(format)]`, which is also
+///   the way out for arguments that can't be cloned (e.g., a `Mutex<u8>`), since those are an
+///   error on the argument otherwise.
+/// - Since the mock can't add bounds to a method, arguments of its type parameters or
+///   `impl Trait` have to be bounded by `Debug` by the method itself (e.g.,
+///   `fn foo<T: Debug>(&self, t: T)` or `fn foo(&self, t: impl Debug)`).
+/// - Methods that return something panic if no value is queued for them.
+/// - The mock only uses `&self`, so it works the same through any receiver (including
+///   `self: Box<Self>` and `async fn`s) and shared pointers.
+///
+/// Methods with a default implementation are inherited, unless marked with `#[mock_impl(mock)]`.
+/// Note that [`pointer_impls`](macro@pointer_impls) inherits them as well, so mark them with
+/// `#[pointer_impl(forward)]` too to have pointers call the mock. Associated constants need a
+/// default or a value given with `#[mock_impl(value = ...)]`.
+///
+/// Mocked methods are rejected if they have no `self`, have arguments that can't be recorded
+/// (i.e., mentioning `Self`, or not known to be `Debug` as above), or return something that can't
+/// be queued (i.e., anything borrowing, or mentioning `Self`, `impl Trait` or type parameters of
+/// the method). Generic associated types are rejected too. Finally, note that any supertraits of
+/// the trait have to be implemented for `MockFoo` manually.
+///
+/// For example, this fails because the `Mutex` can't be cloned to record it:
+/// ```compile_fail
+/// use std::sync::Mutex;
+///
+/// use auto_traits::mock_impls;
+///
+/// #[mock_impls]
+/// trait Foo {
+///     fn foo(&self, lock: Mutex<u8>);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn mock_impls(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match mock_impls::mock_impls(attr.into(), item.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Implementation detail of [`delegate`](macro@delegate), called by the description of a trait
+/// generated by [`delegate_impls`](macro@delegate_impls).
+#[doc(hidden)]
+#[proc_macro]
+pub fn __delegate(input: TokenStream) -> TokenStream {
+    // Pass to the actual implementation
+    match delegate_impls::__delegate(input.into()) {
+        Ok(res) => res.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
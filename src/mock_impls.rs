@@ -0,0 +1,756 @@
+//  MOCK IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:36:10
+//  Last edited:
+//    17 Oct 2026, 10:34:45
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines an attribute macro for generating a mock that records calls
+//!   and returns queued values.
+//
+
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::ext::IdentExt as _;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Attribute, Error, Expr, FnArg, GenericParam, Generics, Ident, ItemTrait, Lifetime, Pat, Path, ReturnType, Token, TraitItem, TraitItemConst,
+    TraitItemFn, Type, TypeImplTrait, TypeParamBound, TypeReference, TypeTraitObject, WhereClause, WherePredicate, parenthesized, parse_quote,
+};
+
+use crate::fn_impls::name_args;
+use crate::fns_impls::camel_case;
+use crate::pointer_impls::{ImplsToDo, has_pending_impls, mentions_self, returns_unit, strip_owned_mut};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether a type can be stored in the mock as-is.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+/// - `generics`: The type parameters of the method, which don't exist outside of it.
+///
+/// # Returns
+/// False if the type borrows something, or mentions `Self`, `impl Trait`, `dyn Trait` or any of
+/// the `generics`.
+fn storable(ty: &Type, generics: &[&Ident]) -> bool {
+    let mut finder = UnstorableFinder { generics, lifetimes: true, found: false };
+    finder.visit_type_mut(&mut ty.clone());
+    !finder.found
+}
+
+/// Checks whether a type that can't be stored in the mock can be formatted with `Debug` instead.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+/// - `method_generics`: The [`Generics`] of the method, whose bounds may say that it's `Debug`.
+/// - `generics`: The type parameters of the method.
+///
+/// # Returns
+/// True if it only borrows for lifetimes of the method (which is fine for formatting), or if the
+/// method's bounds say that it implements `Debug`. False if it mentions `Self`, or anything else
+/// not known to be `Debug`.
+fn formattable(ty: &Type, method_generics: &Generics, generics: &[&Ident]) -> bool {
+    if mentions_self(ty) {
+        return false;
+    }
+    let mut finder = UnstorableFinder { generics, lifetimes: false, found: false };
+    finder.visit_type_mut(&mut ty.clone());
+    !finder.found || bounded_by_debug(ty, method_generics)
+}
+
+/// Checks whether the bounds of a method say that a type implements `Debug`.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+/// - `generics`: The [`Generics`] of the method.
+///
+/// # Returns
+/// True if `ty` is (a reference to) `impl Debug`, `dyn Debug`, or a type bounded by `Debug` in
+/// `generics` (e.g., `T` in `fn foo<T: Debug>(...)`), or false otherwise.
+fn bounded_by_debug(ty: &Type, generics: &Generics) -> bool {
+    let is_debug = |bounds: &Punctuated<TypeParamBound, Token![+]>| {
+        bounds.iter().any(|bound| matches!(bound, TypeParamBound::Trait(t) if t.path.segments.last().is_some_and(|seg| seg.ident == "Debug")))
+    };
+    match ty {
+        Type::Reference(r) => bounded_by_debug(&r.elem, generics),
+        Type::Paren(p) => bounded_by_debug(&p.elem, generics),
+        Type::ImplTrait(t) => is_debug(&t.bounds),
+        Type::TraitObject(t) => is_debug(&t.bounds),
+        ty => {
+            let ty: String = ty.to_token_stream().to_string();
+            generics.type_params().any(|param| param.ident == ty && is_debug(&param.bounds))
+                || generics.where_clause.iter().flat_map(|w| &w.predicates).any(|pred| {
+                    matches!(pred, WherePredicate::Type(pred) if pred.bounded_ty.to_token_stream().to_string() == ty && is_debug(&pred.bounds))
+                })
+        },
+    }
+}
+
+/// Checks whether a type mentions any of the given identifiers (e.g., generics).
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+/// - `idents`: The identifiers to look for.
+///
+/// # Returns
+/// True if it does, or false otherwise.
+fn mentions(ty: &Type, idents: &HashSet<Ident>) -> bool {
+    let mut collector = IdentCollector { idents: HashSet::new() };
+    collector.visit_type_mut(&mut ty.clone());
+    !collector.idents.is_disjoint(idents)
+}
+
+/// Wraps a list of generic parameters or arguments in angle brackets, if there are any.
+///
+/// # Arguments
+/// - `items`: The parameters or arguments.
+///
+/// # Returns
+/// `<A, B, ...>`, or nothing if there are no `items`.
+#[inline]
+fn angled(items: &[TokenStream2]) -> TokenStream2 { if items.is_empty() { TokenStream2::new() } else { quote! { <#(#items),*> } } }
+
+
+
+
+
+/***** VISITORS *****/
+/// Visitor that replaces the associated types of the trait (e.g., `Self::Foo`) by the type
+/// parameters of the mock.
+struct AssocResolver<'a> {
+    /// The names of the associated types.
+    assoc: &'a HashSet<Ident>,
+}
+impl VisitMut for AssocResolver<'_> {
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        if let Type::Path(ty) = node {
+            let ident: Option<&Ident> = match &ty.qself {
+                // `<Self as Foo>::Bar`
+                Some(qself) if matches!(&*qself.ty, Type::Path(q) if q.qself.is_none() && q.path.is_ident("Self")) => {
+                    if ty.path.segments.len() == qself.position + 1 { ty.path.segments.last().map(|seg| &seg.ident) } else { None }
+                },
+                // `Self::Bar`
+                None if ty.path.segments.len() == 2 && ty.path.segments[0].ident == "Self" => Some(&ty.path.segments[1].ident),
+                _ => None,
+            };
+            if let Some(ident) = ident.filter(|ident| self.assoc.contains(*ident)).cloned() {
+                *node = parse_quote! { #ident };
+                return;
+            }
+        }
+        visit_mut::visit_type_mut(self, node)
+    }
+}
+
+/// Visitor that finds anything preventing a type from being stored in the mock.
+struct UnstorableFinder<'a> {
+    /// The type parameters of the method.
+    generics:  &'a [&'a Ident],
+    /// Whether to look for borrows, too.
+    lifetimes: bool,
+    /// Whether we found anything.
+    found:     bool,
+}
+impl VisitMut for UnstorableFinder<'_> {
+    fn visit_lifetime_mut(&mut self, node: &mut Lifetime) { self.found |= self.lifetimes && node.ident != "static"; }
+
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        if let Some(first) = node.segments.first() {
+            self.found |= first.ident == "Self" || self.generics.iter().any(|generic| first.ident == **generic);
+        }
+        visit_mut::visit_path_mut(self, node)
+    }
+
+    fn visit_type_impl_trait_mut(&mut self, _node: &mut TypeImplTrait) { self.found = true; }
+
+    fn visit_type_reference_mut(&mut self, node: &mut TypeReference) {
+        self.found |= self.lifetimes && node.lifetime.is_none();
+        visit_mut::visit_type_reference_mut(self, node)
+    }
+
+    fn visit_type_trait_object_mut(&mut self, _node: &mut TypeTraitObject) { self.found = true; }
+}
+
+/// Visitor that collects the identifiers used in a type (e.g., to find which generics it uses).
+struct IdentCollector {
+    /// The identifiers found.
+    idents: HashSet<Ident>,
+}
+impl VisitMut for IdentCollector {
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        if let Some(ident) = node.get_ident() {
+            self.idents.insert(ident.clone());
+        }
+        visit_mut::visit_path_mut(self, node)
+    }
+}
+
+
+
+
+
+/***** PARSING *****/
+/// Specifies the attributes we're parsing from the `#[mock_impls(...)]`-attribute.
+struct MockAttributes {
+    /// The traits to derive for the enum of calls.
+    derives: Punctuated<Path, Token![,]>,
+}
+impl Parse for MockAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut derives: Punctuated<Path, Token![,]> = Punctuated::new();
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "derive" {
+                return Err(Error::new(key.span(), format!("Unknown mock_impls attribute '{key}' (expected 'derive')")));
+            }
+            let content;
+            parenthesized!(content in input);
+            derives.extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Self { derives })
+    }
+}
+
+/// Specifies the attributes users can give on trait items.
+#[derive(Default)]
+struct MockItemAttributes {
+    /// Whether to format all arguments of a method instead of cloning them.
+    format: Option<Span>,
+    /// Whether to mock a method with a default implementation.
+    mock:   Option<Span>,
+    /// The value of an associated constant.
+    value:  Option<Expr>,
+}
+impl MockItemAttributes {
+    /// Parses the `#[mock_impl(...)]`-attributes from a list of attributes, removing them.
+    ///
+    /// # Arguments
+    /// - `attrs`: The list of [`Attribute`]s to parse from.
+    ///
+    /// # Returns
+    /// A new MockItemAttributes with what was found.
+    ///
+    /// # Errors
+    /// This function errors if any of the attributes are invalid.
+    fn extract(attrs: &mut Vec<Attribute>) -> syn::Result<Self> {
+        let mut res = Self::default();
+        let mut err: Option<Error> = None;
+        attrs.retain(|attr| {
+            if !attr.path().is_ident("mock_impl") {
+                return true;
+            }
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("format") {
+                    res.format = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("mock") {
+                    res.mock = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("value") {
+                    res.value = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("Unknown mock_impl attribute {}", meta.path.to_token_stream())))
+                }
+            });
+            if let Err(e) = parsed {
+                err = Some(e);
+            }
+            false
+        });
+        match err {
+            Some(err) => Err(err),
+            None => Ok(res),
+        }
+    }
+}
+
+
+
+
+
+/***** GENERATION *****/
+/// Describes how an argument is recorded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Record {
+    /// By cloning it.
+    Clone,
+    /// By calling `ToOwned::to_owned()` on the referenced value.
+    ToOwned,
+    /// By formatting it with `Debug`.
+    Format,
+}
+
+/// Describes an argument of a mocked method.
+struct Arg {
+    /// The name of the field storing it in the enum of calls.
+    name:   Ident,
+    /// The type of the field storing it.
+    stored: Type,
+    /// The bound required to record it, if any.
+    bound:  Option<WherePredicate>,
+    /// A check that it can be recorded, if that doesn't depend on the generics of the mock.
+    check:  Option<TokenStream2>,
+    /// How it's recorded.
+    record: Record,
+    /// The span of its type, which is blamed if it can't be recorded.
+    span:   Span,
+}
+
+/// Describes a mocked method.
+struct Method<'s> {
+    /// The method itself.
+    method:  &'s TraitItemFn,
+    /// The name of its variant in the enum of calls.
+    variant: Ident,
+    /// Its arguments.
+    args:    Vec<Arg>,
+    /// The type of values to queue for it, if it returns anything.
+    output:  Option<Type>,
+}
+impl<'s> Method<'s> {
+    /// Analyzes how to mock a method.
+    ///
+    /// # Arguments
+    /// - `method`: The method to mock.
+    /// - `attrs`: The [`MockItemAttributes`] given to it.
+    /// - `assoc`: The names of the associated types of the trait.
+    /// - `params`: The names of the type- and const parameters of the mock.
+    ///
+    /// # Returns
+    /// A new Method describing how to mock it.
+    ///
+    /// # Errors
+    /// This function errors with the reason why the method can't be mocked.
+    fn new(method: &'s TraitItemFn, attrs: Option<&MockItemAttributes>, assoc: &HashSet<Ident>, params: &HashSet<Ident>) -> syn::Result<Self> {
+        let sig = &method.sig;
+        let ident: &Ident = &sig.ident;
+        let generics: Vec<&Ident> = sig.generics.type_params().map(|param| &param.ident).collect();
+        if !matches!(sig.inputs.first(), Some(FnArg::Receiver(_))) {
+            return Err(Error::new(ident.span(), format!("Cannot mock method '{ident}' because it has no receiver to record the call on")));
+        }
+
+        // Find how to record the arguments
+        let format: bool = attrs.is_some_and(|attrs| attrs.format.is_some());
+        let mut args: Vec<Arg> = Vec::with_capacity(sig.inputs.len());
+        for arg in sig.inputs.iter().skip(1) {
+            let FnArg::Typed(arg) = arg else { unreachable!() };
+            let name: Ident = match &*arg.pat {
+                Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => pat.ident.clone(),
+                _ => format_ident!("arg{}", args.len()),
+            };
+            let mut ty: Type = (*arg.ty).clone();
+            AssocResolver { assoc }.visit_type_mut(&mut ty);
+            let string: Type = parse_quote! { ::std::string::String };
+            let formatted = |ty: &Type| -> syn::Result<(Type, Option<WherePredicate>, Record)> {
+                // We can only require `Debug` of types that exist outside of the method. Others
+                // have to be bounded by the method itself.
+                if storable(ty, &generics) {
+                    Ok((string.clone(), Some(parse_quote! { #ty: ::core::fmt::Debug }), Record::Format))
+                } else if formattable(ty, &sig.generics, &generics) {
+                    Ok((string.clone(), None, Record::Format))
+                } else if mentions_self(ty) {
+                    Err(Error::new(arg.ty.span(), format!("Cannot mock method '{ident}' because its argument '{name}' mentions `Self`, which can't be recorded")))
+                } else {
+                    Err(Error::new(
+                        arg.ty.span(),
+                        format!(
+                            "Cannot mock method '{ident}' because its argument '{name}' can't be cloned, and isn't known to implement `Debug` to \
+                             format it instead (bound it by `Debug` in the method)"
+                        ),
+                    ))
+                }
+            };
+            let (stored, bound, record): (Type, Option<WherePredicate>, Record) = match &ty {
+                ty if format => formatted(ty)?,
+                ty if storable(ty, &generics) => (ty.clone(), Some(parse_quote! { #ty: ::core::clone::Clone }), Record::Clone),
+                Type::Reference(r) if storable(&r.elem, &generics) => match &*r.elem {
+                    Type::Path(p) if p.qself.is_none() && p.path.is_ident("str") => (string, None, Record::ToOwned),
+                    Type::Slice(s) => {
+                        let elem = &s.elem;
+                        (parse_quote! { ::std::vec::Vec<#elem> }, Some(parse_quote! { #elem: ::core::clone::Clone }), Record::ToOwned)
+                    },
+                    // The owned version of generics is only known through bounds on the enum of
+                    // calls, so we clone those instead
+                    elem if mentions(elem, params) => (elem.clone(), Some(parse_quote! { #elem: ::core::clone::Clone }), Record::ToOwned),
+                    elem => (parse_quote! { <#elem as ::std::borrow::ToOwned>::Owned }, None, Record::ToOwned),
+                },
+                ty => formatted(ty)?,
+            };
+            // If the bound doesn't involve the generics, a bound on the impl would only fail with an
+            // error about the whole trait. So we check it on its own, and blame the argument.
+            let (bound, check): (Option<WherePredicate>, Option<TokenStream2>) = match bound {
+                Some(WherePredicate::Type(pred)) if !mentions(&pred.bounded_ty, params) => {
+                    let (ty, bounds) = (&pred.bounded_ty, &pred.bounds);
+                    let check: TokenStream2 = quote_spanned! { arg.ty.span() =>
+                        const _: fn() = || {
+                            fn check<T: #bounds>() {}
+                            check::<#ty>();
+                        };
+                    };
+                    (None, Some(check))
+                },
+                bound => (bound, None),
+            };
+            args.push(Arg { name, stored, bound, check, record, span: arg.ty.span() });
+        }
+
+        // Find what to queue for it
+        let output: Option<Type> = match &sig.output {
+            output if returns_unit(output) => None,
+            ReturnType::Type(_, ty) => {
+                let mut ty: Type = (**ty).clone();
+                AssocResolver { assoc }.visit_type_mut(&mut ty);
+                if !storable(&ty, &generics) {
+                    return Err(Error::new(
+                        ty.span(),
+                        format!("Cannot mock method '{ident}' because its return type can't be queued (it may not borrow, or mention `Self` or `impl Trait`)"),
+                    ));
+                }
+                Some(ty)
+            },
+            ReturnType::Default => unreachable!(),
+        };
+
+        Ok(Self { method, variant: format_ident!("{}", camel_case(ident)), args, output })
+    }
+
+    /// Returns the name of the field in the mock with the queued return values.
+    #[inline]
+    fn queue(&self) -> Ident { format_ident!("{}_returns", self.method.sig.ident.unraw()) }
+}
+
+/// Implements the main struct doing the heavy lifting.
+struct Generator<'s> {
+    /// What we parsed from the attribute stream.
+    attrs:   MockAttributes,
+    /// The definition of the trait.
+    def:     &'s ItemTrait,
+    /// The associated types of the trait, and their bounds.
+    assoc:   Vec<(&'s Ident, TokenStream2)>,
+    /// The associated constants given a value.
+    consts:  Vec<(&'s TraitItemConst, &'s Expr)>,
+    /// The mocked methods.
+    methods: Vec<Method<'s>>,
+}
+impl Generator<'_> {
+    /// Generates the mock, the enum of calls and the trait implementation.
+    ///
+    /// # Arguments
+    /// - `tokens`: The [`TokenStream2`] to write them to.
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let def: &ItemTrait = self.def;
+        let name: &Ident = &def.ident;
+        let vis = &def.vis;
+        let mock = format_ident!("Mock{name}");
+        let call = format_ident!("{name}Call");
+        let returns = format_ident!("Mock{name}Returns");
+
+        // The mock is generic over the types and constants of the trait, plus its associated types
+        let mut params: Vec<(&Ident, TokenStream2, TokenStream2)> = Vec::new();
+        for param in &def.generics.params {
+            match param {
+                GenericParam::Lifetime(_) => {},
+                GenericParam::Type(t) => params.push((&t.ident, t.ident.to_token_stream(), t.ident.to_token_stream())),
+                GenericParam::Const(c) => {
+                    let (ident, ty) = (&c.ident, &c.ty);
+                    params.push((ident, quote! { const #ident: #ty }, quote! { [(); #ident] }));
+                },
+            }
+        }
+        params.extend(self.assoc.iter().map(|(ident, _)| (*ident, ident.to_token_stream(), ident.to_token_stream())));
+        let mock_params: Vec<TokenStream2> = params.iter().map(|(_, param, _)| param.clone()).collect();
+        let mock_args: Vec<TokenStream2> = params.iter().map(|(ident, _, _)| ident.to_token_stream()).collect();
+        let markers: Vec<&TokenStream2> = params.iter().map(|(_, _, marker)| marker).collect();
+        let (mock_params, mock_args) = (angled(&mock_params), angled(&mock_args));
+
+        // The enum of calls only needs those used by the arguments
+        let mut used = IdentCollector { idents: HashSet::new() };
+        for arg in self.methods.iter().flat_map(|m| &m.args) {
+            used.visit_type_mut(&mut arg.stored.clone());
+        }
+        let call_params: Vec<TokenStream2> =
+            params.iter().filter(|(ident, _, _)| used.idents.contains(*ident)).map(|(_, param, _)| param.clone()).collect();
+        let call_args: Vec<TokenStream2> =
+            params.iter().filter(|(ident, _, _)| used.idents.contains(*ident)).map(|(ident, _, _)| ident.to_token_stream()).collect();
+        let (call_params, call_args) = (angled(&call_params), angled(&call_args));
+
+        // Generate the enum of calls
+        let variants = self.methods.iter().map(|m| {
+            let doc: String = format!(" A call to [`{name}::{}()`].", m.method.sig.ident.unraw());
+            let variant: &Ident = &m.variant;
+            let fields = m.args.iter().map(|arg| {
+                let (name, stored) = (&arg.name, &arg.stored);
+                quote! { #name: #stored }
+            });
+            if m.args.is_empty() { quote! { #[doc = #doc] #variant } } else { quote! { #[doc = #doc] #variant { #(#fields),* } } }
+        });
+        let derives: Option<TokenStream2> = if self.attrs.derives.is_empty() {
+            None
+        } else {
+            let derives = &self.attrs.derives;
+            Some(quote! { #[derive(#derives)] })
+        };
+        let call_doc: String = format!(" The calls made to a [`{mock}`].");
+        tokens.extend(quote! {
+            #[doc = #call_doc]
+            #[allow(dead_code)]
+            #derives
+            #vis enum #call #call_params {
+                #(#variants),*
+            }
+        });
+
+        // Generate the mock itself
+        let queues: Vec<Ident> = self.methods.iter().filter(|m| m.output.is_some()).map(Method::queue).collect();
+        let outputs: Vec<&Type> = self.methods.iter().filter_map(|m| m.output.as_ref()).collect();
+        let marker = Ident::new("_marker", Span::call_site());
+        let mock_doc: String = format!(" A mock implementing [`{name}`], which records every call and returns the values queued for it.");
+        tokens.extend(quote! {
+            #[doc = #mock_doc]
+            #[allow(dead_code)]
+            #vis struct #mock #mock_params {
+                calls: ::std::sync::Mutex<::std::vec::Vec<#call #call_args>>,
+                #(#queues: ::std::sync::Mutex<::std::collections::VecDeque<#outputs>>,)*
+                #marker: ::core::marker::PhantomData<fn() -> (#(#markers,)*)>,
+            }
+        });
+
+        // Then the ways to use it
+        let lock = quote! { .lock().unwrap_or_else(::std::sync::PoisonError::into_inner) };
+        let expects = self.methods.iter().filter_map(|m| {
+            let ident: &Ident = &m.method.sig.ident;
+            let doc: String = format!(" Returns a handle to queue the values returned by [`{name}::{}()`].", ident.unraw());
+            let expect = format_ident!("expect_{}", ident.unraw());
+            let (queue, output) = (m.queue(), m.output.as_ref()?);
+            Some(quote! {
+                #[doc = #doc]
+                #[inline]
+                #vis fn #expect(&self) -> #returns<'_, #output> { #returns { queue: &self.#queue } }
+            })
+        });
+        let new_doc: String = format!(" Creates a new {mock} without any calls or queued return values.");
+        let calls_doc: String = " Returns the calls made to this mock so far, in order.".to_string();
+        tokens.extend(quote! {
+            #[allow(dead_code)]
+            impl #mock_params #mock #mock_args {
+                #[doc = #new_doc]
+                #[inline]
+                #vis fn new() -> Self {
+                    Self {
+                        calls: ::core::default::Default::default(),
+                        #(#queues: ::core::default::Default::default(),)*
+                        #marker: ::core::marker::PhantomData,
+                    }
+                }
+
+                #[doc = #calls_doc]
+                #[inline]
+                #vis fn calls(&self) -> ::std::sync::MutexGuard<'_, ::std::vec::Vec<#call #call_args>> { self.calls #lock }
+
+                #(#expects)*
+            }
+            impl #mock_params ::core::default::Default for #mock #mock_args {
+                #[inline]
+                fn default() -> Self { Self::new() }
+            }
+        });
+        if !queues.is_empty() {
+            let returns_doc: String = format!(" Queues the values returned by a method of a [`{mock}`].");
+            let returning_doc: String = " Queues a value to return from a call to the method, after those queued before.".to_string();
+            tokens.extend(quote! {
+                #[doc = #returns_doc]
+                #[allow(dead_code)]
+                #vis struct #returns<'m, R> {
+                    queue: &'m ::std::sync::Mutex<::std::collections::VecDeque<R>>,
+                }
+                #[allow(dead_code)]
+                impl<R> #returns<'_, R> {
+                    #[doc = #returning_doc]
+                    #[inline]
+                    #vis fn returning(self, value: R) -> Self {
+                        self.queue #lock.push_back(value);
+                        self
+                    }
+                }
+            });
+        }
+
+        // Finally, implement the trait
+        let this = Ident::new("self", Span::call_site());
+        let mut items: Vec<TokenStream2> = Vec::new();
+        for (ident, _) in &self.assoc {
+            items.push(quote! { type #ident = #ident; });
+        }
+        for (TraitItemConst { attrs, const_token, ident, colon_token, ty, semi_token, .. }, value) in &self.consts {
+            items.push(quote! { #(#attrs)* #const_token #ident #colon_token #ty = #value #semi_token });
+        }
+        let mut where_clause: WhereClause = def.generics.where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+        where_clause.predicates.extend(self.assoc.iter().filter(|(_, bounds)| !bounds.is_empty()).map(|(ident, bounds)| -> WherePredicate {
+            parse_quote! { #ident: #bounds }
+        }));
+        for m in &self.methods {
+            let TraitItemFn { attrs, sig, .. } = m.method;
+            let mut sig = sig.clone();
+            let names: Vec<Ident> = name_args(&mut sig).into_iter().map(|(ident, _)| ident).collect();
+            // We don't need mutable `self` if we move it
+            strip_owned_mut(&mut sig);
+
+            // Record the arguments (which need to be cloneable or formattable)
+            let mut fields: Vec<TokenStream2> = Vec::with_capacity(m.args.len());
+            for (arg, value) in m.args.iter().zip(&names) {
+                let name: &Ident = &arg.name;
+                let value: TokenStream2 = match arg.record {
+                    Record::Clone => quote_spanned! { arg.span => ::core::clone::Clone::clone(&#value) },
+                    Record::ToOwned => quote_spanned! { arg.span => ::std::borrow::ToOwned::to_owned(&*#value) },
+                    Record::Format => quote_spanned! { arg.span => ::std::format!("{:?}", #value) },
+                };
+                where_clause.predicates.extend(arg.bound.clone());
+                fields.push(quote! { #name: #value });
+            }
+            let variant: &Ident = &m.variant;
+            let record: TokenStream2 = if fields.is_empty() { quote! { #call::#variant } } else { quote! { #call::#variant { #(#fields),* } } };
+
+            // Then return the queued value
+            let ret: Option<TokenStream2> = m.output.as_ref().map(|_| {
+                let queue: Ident = m.queue();
+                let ident: Ident = sig.ident.unraw();
+                let msg: String = format!("{mock}::{ident}() was called, but no value was queued for it with `expect_{ident}().returning(...)`");
+                quote! { #this.#queue #lock.pop_front().unwrap_or_else(|| ::core::panic!(#msg)) }
+            });
+            items.push(quote! {
+                #(#attrs)*
+                #sig {
+                    #this.calls #lock.push(#record);
+                    #ret
+                }
+            });
+        }
+        let assoc_params: Vec<GenericParam> = self.assoc.iter().map(|(ident, _)| parse_quote! { #ident }).collect();
+        let mut generics = def.generics.clone();
+        generics.params.extend(assoc_params);
+        let (impl_gen, _, _) = generics.split_for_impl();
+        let (_, trait_ty_gen, _) = def.generics.split_for_impl();
+        let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+        let checks = self.methods.iter().flat_map(|m| &m.args).filter_map(|arg| arg.check.as_ref());
+        tokens.extend(quote! {
+            #unsafety impl #impl_gen #name #trait_ty_gen for #mock #mock_args #where_clause {
+                #(#items)*
+            }
+
+            #(#checks)*
+        });
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `mock_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + a mock implementing it.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn mock_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    let attrs: MockAttributes = syn::parse2(attr)?;
+
+    // Take our own attributes off of the items first, and then parse the rest like usual
+    let mut def: ItemTrait = syn::parse2(item)?;
+    let mut item_attrs: HashMap<usize, MockItemAttributes> = HashMap::new();
+    for (i, item) in def.items.iter_mut().enumerate() {
+        let (attrs, is_fn, is_const): (&mut Vec<Attribute>, bool, bool) = match item {
+            TraitItem::Const(c) => (&mut c.attrs, false, true),
+            TraitItem::Fn(f) => (&mut f.attrs, true, false),
+            TraitItem::Type(ty) => (&mut ty.attrs, false, false),
+            _ => continue,
+        };
+        let attrs = MockItemAttributes::extract(attrs)?;
+        if let (Some(span), false) = (attrs.format.or(attrs.mock), is_fn) {
+            return Err(Error::new(span, "Only methods can be formatted or mocked"));
+        }
+        if let (Some(value), false) = (&attrs.value, is_const) {
+            return Err(Error::new(value.span(), "Only associated constants can be given a value"));
+        }
+        item_attrs.insert(i, attrs);
+    }
+    let todo: ImplsToDo = syn::parse2(def.to_token_stream())?;
+
+    // Write the definition itself. If any of our other macros still has to run, it needs the
+    // `#[pointer_impl(...)]`-attributes.
+    let mut tokens: TokenStream2 = if has_pending_impls(&def) { def.to_token_stream() } else { todo.def.to_token_stream() };
+
+    // Go through the items to find what to mock. Methods with defaults are inherited unless asked
+    // otherwise.
+    let assoc_names: HashSet<Ident> =
+        todo.def.items.iter().filter_map(|item| if let TraitItem::Type(ty) = item { Some(ty.ident.clone()) } else { None }).collect();
+    let mut params: HashSet<Ident> = todo.def.generics.type_params().map(|param| param.ident.clone()).collect();
+    params.extend(todo.def.generics.const_params().map(|param| param.ident.clone()));
+    params.extend(assoc_names.iter().cloned());
+    let mut assoc: Vec<(&Ident, TokenStream2)> = Vec::new();
+    let mut consts: Vec<(&TraitItemConst, &Expr)> = Vec::new();
+    let mut methods: Vec<Method> = Vec::new();
+    for (i, item) in todo.def.items.iter().enumerate() {
+        let attrs: Option<&MockItemAttributes> = item_attrs.get(&i);
+        match item {
+            TraitItem::Type(ty) => {
+                if !ty.generics.params.is_empty() {
+                    return Err(Error::new(ty.generics.span(), format!("Generic associated type '{}' is not supported by `#[mock_impls]`", ty.ident)));
+                }
+                let bounds = &ty.bounds;
+                assoc.push((&ty.ident, bounds.to_token_stream()));
+            },
+
+            TraitItem::Const(c) => match (attrs.and_then(|attrs| attrs.value.as_ref()), &c.default) {
+                (Some(value), _) => consts.push((c, value)),
+                (None, Some(_)) => {},
+                (None, None) => {
+                    return Err(Error::new(
+                        c.ident.span(),
+                        format!("Cannot mock associated constant '{}' without a default (give it with `#[mock_impl(value = ...)]`)", c.ident),
+                    ));
+                },
+            },
+
+            TraitItem::Fn(f) => {
+                let mock: bool = attrs.is_some_and(|attrs| attrs.mock.is_some());
+                if f.default.is_some() && !mock {
+                    continue;
+                }
+                match Method::new(f, attrs, &assoc_names, &params) {
+                    Ok(method) => methods.push(method),
+                    Err(err) if f.default.is_some() => {
+                        return Err(Error::new(err.span(), format!("{err}; don't mark it with `#[mock_impl(mock)]` to inherit its default instead")));
+                    },
+                    Err(err) => return Err(err),
+                }
+            },
+
+            _ => {},
+        }
+    }
+
+    // Now generate the mock
+    Generator { attrs, def: &todo.def, assoc, consts, methods }.to_tokens(&mut tokens);
+    Ok(tokens)
+}
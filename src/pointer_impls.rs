@@ -4,7 +4,7 @@
 //  Created:
 //    13 Dec 2024, 14:22:51
 //  Last edited:
-//    15 Jan 2025, 10:41:07
+//    17 Oct 2026, 10:22:08
 //  Auto updated?
 //    Yes
 //
@@ -14,19 +14,23 @@
 //
 
 use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FResult};
 
 use bitvec::prelude::BitVec;
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::{FoundCrate, crate_name};
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
 use quote::{ToTokens, quote};
-use syn::parse::{Parse, ParseStream};
+use syn::ext::IdentExt as _;
+use syn::parse::{Parse, ParseStream, Parser as _};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned as _;
-use syn::token::Brace;
+use syn::token::{Brace, Bracket, Paren};
 use syn::visit_mut::{self, VisitMut};
 use syn::{
-    AngleBracketedGenericArguments, Attribute, Error, Expr, ExprPath, FnArg, GenericArgument, GenericParam, Generics, Ident, ItemTrait, Lifetime,
-    LifetimeParam, Meta, MetaList, Pat, Path, PathArguments, PathSegment, Token, TraitBound, TraitBoundModifier, TraitItem, TraitItemConst,
-    TraitItemFn, TraitItemType, Type, TypeInfer, TypeParam, TypeParamBound, TypePath, TypeReference,
+    AngleBracketedGenericArguments, Attribute, Error, Expr, ExprPath, FnArg, GenericArgument, GenericParam, Generics, Ident, ItemTrait,
+    MacroDelimiter, Meta, MetaList, Pat, Path, PathArguments, PathSegment, PredicateType, Receiver, ReturnType, Signature, Token, TraitBound,
+    TraitBoundModifier, TraitItem, TraitItemConst, TraitItemFn, TraitItemType, Type, TypeParam, TypeParamBound, TypePath, TypeReference, WhereClause,
+    WherePredicate, braced, parse_quote,
 };
 
 
@@ -36,555 +40,224 @@ use syn::{
 /// # Returns
 /// A set of [`TypeToImpl`]s that describe the implementations to generate for the default types.
 fn default_types() -> HashSet<TypeToImpl> {
-    HashSet::from([
-        // &'a _
-        TypeToImpl {
-            ty: Type::Reference(TypeReference {
-                and_token: Default::default(),
-                lifetime: Some(Lifetime { apostrophe: Span::call_site(), ident: Ident::new("a", Span::call_site()) }),
-                mutability: None,
-                elem: Box::new(Type::Infer(TypeInfer { underscore_token: Default::default() })),
-            }),
-            mutable: false,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // &'a mut _
-        TypeToImpl {
-            ty: Type::Reference(TypeReference {
-                and_token: Default::default(),
-                lifetime: Some(Lifetime { apostrophe: Span::call_site(), ident: Ident::new("a", Span::call_site()) }),
-                mutability: Some(Default::default()),
-                elem: Box::new(Type::Infer(TypeInfer { underscore_token: Default::default() })),
-            }),
-            mutable: true,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::std::boxed::Box<_>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("boxed", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("Box", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: true,
-            generics: None,
-            closure: None,
-        },
-        // ::std::rc::Rc<_>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("rc", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("Rc", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: false,
-            generics: None,
-            closure: None,
-        },
-        // ::std::sync::Arc<_>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("sync", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("Arc", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: false,
-            generics: None,
-            closure: None,
-        },
-        // ::std::cell::Ref<'a, _>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("cell", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("Ref", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: false,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::std::cell::RefMut<'a, _>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("cell", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("RefMut", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: true,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::std::sync::MutexGuard<'a, _>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("sync", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("MutexGuard", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: true,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::std::sync::RwLockReadGuard<'a, _>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("sync", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("RwLockReadGuard", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: false,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::std::sync::RwLockWriteGuard<'a, _>
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("std", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment { ident: Ident::new("sync", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("RwLockWriteGuard", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: true,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::parking_lot::MutexGuard<'a, _>
-        #[cfg(feature = "parking_lot")]
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("parking_lot", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("MutexGuard", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: true,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
-        },
-        // ::parking_lot::RwLockReadGuard<'a, _>
-        #[cfg(feature = "parking_lot")]
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("parking_lot", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("RwLockReadGuard", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: false,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
-                },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
+    Group::ALL.into_iter().filter(|group| group.is_default()).flat_map(|group| group.types().unwrap_or_default()).collect()
+}
+
+/// Finds the path by which the crate invoking the macro refers to one of its dependencies.
+///
+/// This reads the invoking crate's `Cargo.toml`, such that renamed dependencies are found too.
+///
+/// # Arguments
+/// - `name`: The name of the dependency as published (e.g., `parking_lot`).
+///
+/// # Returns
+/// A [`Path`] that refers to the dependency's root (e.g., `::parking_lot`).
+///
+/// # Errors
+/// This function errors if the invoking crate doesn't depend on `name`.
+fn dependency_path(name: &str) -> Result<Path, String> {
+    match crate_name(name) {
+        Ok(FoundCrate::Itself) => Ok(parse_quote! { crate }),
+        Ok(FoundCrate::Name(name)) => {
+            let name = Ident::new(&name, Span::call_site());
+            Ok(parse_quote! { ::#name })
         },
-        // ::parking_lot::RwLockWriteGuard<'a, _>
-        #[cfg(feature = "parking_lot")]
-        TypeToImpl {
-            ty: Type::Path(TypePath {
-                qself: None,
-                path:  Path {
-                    leading_colon: Some(Default::default()),
-                    segments:      {
-                        let mut punct = Punctuated::new();
-                        punct.push(PathSegment { ident: Ident::new("parking_lot", Span::call_site()), arguments: PathArguments::None });
-                        punct.push(PathSegment {
-                            ident:     Ident::new("RwLockWriteGuard", Span::call_site()),
-                            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                                colon2_token: None,
-                                lt_token: Default::default(),
-                                args: {
-                                    let mut punct = Punctuated::new();
-                                    punct.push(GenericArgument::Lifetime(Lifetime {
-                                        apostrophe: Span::call_site(),
-                                        ident:      Ident::new("a", Span::call_site()),
-                                    }));
-                                    punct.push(GenericArgument::Type(Type::Infer(TypeInfer { underscore_token: Default::default() })));
-                                    punct
-                                },
-                                gt_token: Default::default(),
-                            }),
-                        });
-                        punct
-                    },
-                },
-            }),
-            mutable: true,
-            generics: Some(Generics {
-                lt_token: Some(Default::default()),
-                params: {
-                    let mut params = Punctuated::new();
-                    params.push(GenericParam::Lifetime(LifetimeParam {
-                        attrs: Vec::new(),
-                        lifetime: Lifetime::new("'a", Span::call_site()),
-                        colon_token: None,
-                        bounds: Punctuated::new(),
-                    }));
-                    params
+        Err(err) => Err(format!("Failed to find dependency `{name}` of the current crate: {err}")),
+    }
+}
+
+/// Checks whether a trait still has another one of our attributes to expand, which needs the
+/// `#[pointer_impl(...)]`-attributes on its items.
+///
+/// # Arguments
+/// - `def`: The [`ItemTrait`] to check.
+///
+/// # Returns
+/// True if it does, or false otherwise.
+pub(crate) fn has_pending_impls(def: &ItemTrait) -> bool {
+    def.attrs.iter().any(|attr| {
+        attr.path().segments.last().is_some_and(|seg| {
+            seg.ident == "pointer_impls"
+                || seg.ident == "delegate_impls"
+                || seg.ident == "tuple_impls"
+                || seg.ident == "collection_impls"
+                || seg.ident == "mock_impls"
+        })
+    })
+}
+
+/// Checks whether the given type is `Self`.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+///
+/// # Returns
+/// True if it's exactly `Self`, or false otherwise.
+#[inline]
+fn is_self(ty: &Type) -> bool { matches!(ty, Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("Self")) }
+
+/// Defines the standard library types we can refer to by name only, and the module they live in.
+const STD_TYPES: [(&str, &str); 15] = [
+    ("Box", "boxed"),
+    ("Rc", "rc"),
+    ("Arc", "sync"),
+    ("Ref", "cell"),
+    ("RefMut", "cell"),
+    ("MutexGuard", "sync"),
+    ("RwLockReadGuard", "sync"),
+    ("RwLockWriteGuard", "sync"),
+    ("Cow", "borrow"),
+    ("Pin", "pin"),
+    ("ManuallyDrop", "mem"),
+    ("AssertUnwindSafe", "panic"),
+    ("LazyLock", "sync"),
+    ("LazyCell", "cell"),
+    ("ReentrantLockGuard", "sync"),
+];
+
+/// Computes the edit distance between two strings.
+///
+/// # Arguments
+/// - `lhs`: The first string.
+/// - `rhs`: The second string.
+///
+/// # Returns
+/// The number of single-character insertions, deletions or substitutions to go from `lhs` to `rhs`.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut prev: Vec<usize> = (0..=rhs.len()).collect();
+    for (i, l) in lhs.chars().enumerate() {
+        let mut next: Vec<usize> = Vec::with_capacity(prev.len());
+        next.push(i + 1);
+        for (j, r) in rhs.iter().enumerate() {
+            next.push((prev[j] + usize::from(l != *r)).min(prev[j + 1] + 1).min(next[j] + 1));
+        }
+        prev = next;
+    }
+    prev[rhs.len()]
+}
+
+/// Normalizes a type such that different ways to write the same type become equal.
+///
+/// Specifically, this resolves standard library types to their full path (e.g., `Box` and
+/// `alloc::boxed::Box` both become `std::boxed::Box`), removes any leading colons and removes
+/// lifetimes.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to normalize.
+///
+/// # Returns
+/// A normalized version of `ty` that can be compared to other normalized types.
+fn normalize_type(ty: &Type) -> Type {
+    let mut ty: Type = ty.clone();
+    TypeNormalizer.visit_type_mut(&mut ty);
+    ty
+}
+
+/// Renders a type as a string that's a bit nicer on the eyes than its raw token stream.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to render.
+///
+/// # Returns
+/// A string like `::std::boxed::Box<_>` (instead of `:: std :: boxed :: Box < _ >`).
+fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// Renders the (normalized) name of a type without the path leading up to it.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to render.
+///
+/// # Returns
+/// A string like `Box<_>`, which can be used to find types similar to it.
+fn short_type_name(ty: &Type) -> String {
+    match normalize_type(ty) {
+        Type::Path(TypePath { qself: None, path }) if !path.segments.is_empty() => path.segments[path.segments.len() - 1].to_token_stream().to_string(),
+        ty => ty.to_token_stream().to_string(),
+    }
+}
+
+/// Checks whether the given path refers to a particular standard library type.
+///
+/// This accepts both the prelude name (e.g., `Box`) and the full path from `std`, `alloc` or `core`
+/// (e.g., `::std::boxed::Box` or `alloc::boxed::Box`). Generic arguments are ignored.
+///
+/// # Arguments
+/// - `path`: The [`Path`] to check.
+/// - `module`: The module in the standard library where the type lives (e.g., `boxed`).
+/// - `name`: The name of the type itself (e.g., `Box`).
+///
+/// # Returns
+/// True if it refers to that type, or false otherwise.
+fn is_std_path(path: &Path, module: &str, name: &str) -> bool {
+    let segments: Vec<&PathSegment> = path.segments.iter().collect();
+    match segments.as_slice() {
+        [ty] => path.leading_colon.is_none() && ty.ident == name,
+        [root, m, ty] => (root.ident == "std" || root.ident == "alloc" || root.ident == "core") && m.ident == module && ty.ident == name,
+        _ => false,
+    }
+}
+
+/// Extracts the single type argument given to some path, e.g., `Self` in `Box<Self>`.
+///
+/// # Arguments
+/// - `path`: The [`Path`] to extract from.
+///
+/// # Returns
+/// The only [`Type`] given as generic argument to the path's last segment, or [`None`] if there
+/// isn't exactly one.
+fn single_type_arg(path: &Path) -> Option<&Type> {
+    let PathArguments::AngleBracketed(args) = &path.segments.last()?.arguments else { return None };
+    if args.args.len() != 1 {
+        return None;
+    }
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Checks whether the given generics restrict their item to `Self: Sized`.
+///
+/// # Arguments
+/// - `generics`: The [`Generics`] of which to check the where-clause.
+///
+/// # Returns
+/// True if there is a `where Self: Sized` bound, or false otherwise.
+fn requires_sized(generics: &Generics) -> bool {
+    let Some(where_clause) = &generics.where_clause else { return false };
+    where_clause.predicates.iter().any(|pred| match pred {
+        WherePredicate::Type(PredicateType { bounded_ty: Type::Path(ty), bounds, .. }) if ty.qself.is_none() && ty.path.is_ident("Self") => {
+            bounds.iter().any(|bound| match bound {
+                TypeParamBound::Trait(TraitBound { modifier: TraitBoundModifier::None, path, .. }) => {
+                    path.segments.last().map(|s| s.ident == "Sized").unwrap_or(false)
                 },
-                gt_token: Some(Default::default()),
-                where_clause: None,
-            }),
-            closure: None,
+                _ => false,
+            })
         },
-    ])
+        _ => false,
+    })
 }
 
 /// Injects additional types into the given generics.
 ///
 /// # Arguments
-/// - `t`: The name of the special `T` to inject as the type bearing the target trait.
+/// - `t`: The name of the special `T` to inject as the type bearing the target trait, or [`None`] if the type to implement for doesn't wrap a `T` (see [`TypeToImpl::target`]).
 /// - `todo`: The [`ItemTrait`] encoding the trait to implement.
 /// - `to_impl`: The type wrapping `T` for which we actually implement. Any of its generics are inject, EXCEPT if they ALREADY OCCUR (including `T`).
+/// - `maybe_unsized`: Whether `T` should be given a `?Sized` bound.
 /// - `generics`: The [`Generics`] to inject in.
-fn inject_additional_types(t: &Ident, todo: &ItemTrait, type_to_impl_gen: &Option<Generics>, generics: &mut Generics) {
+pub(crate) fn inject_additional_types(t: Option<&Ident>, todo: &ItemTrait, type_to_impl_gen: &Option<Generics>, maybe_unsized: bool, generics: &mut Generics) {
     if let Some(type_to_impl_gen) = type_to_impl_gen {
         // Inject lifetimes first
         generics.params = type_to_impl_gen
@@ -606,12 +279,31 @@ fn inject_additional_types(t: &Ident, todo: &ItemTrait, type_to_impl_gen: &Optio
     }
 
     // Push `T`
+    let Some(t) = t else { return };
     generics.params.push(GenericParam::Type(TypeParam {
         attrs: Vec::new(),
         ident: t.clone(),
         colon_token: Some(Default::default()),
         bounds: {
             let mut bounds = Punctuated::new();
+            if maybe_unsized {
+                // `?::core::marker::Sized`, spelled out in full to not depend on the prelude
+                bounds.push(TypeParamBound::Trait(TraitBound {
+                    paren_token: None,
+                    modifier: TraitBoundModifier::Maybe(Default::default()),
+                    lifetimes: None,
+                    path: Path {
+                        leading_colon: Some(Default::default()),
+                        segments:      {
+                            let mut segments = Punctuated::new();
+                            segments.push(PathSegment { ident: Ident::new("core", Span::call_site()), arguments: PathArguments::None });
+                            segments.push(PathSegment { ident: Ident::new("marker", Span::call_site()), arguments: PathArguments::None });
+                            segments.push(PathSegment { ident: Ident::new("Sized", Span::call_site()), arguments: PathArguments::None });
+                            segments
+                        },
+                    },
+                }));
+            }
             bounds.push(TypeParamBound::Trait(TraitBound {
                 paren_token: Some(Default::default()),
                 modifier: TraitBoundModifier::None,
@@ -677,8 +369,275 @@ fn inject_additional_types(t: &Ident, todo: &ItemTrait, type_to_impl_gen: &Optio
 
 
 
+/// Checks whether a type mentions `Self` other than through its associated types.
+///
+/// # Arguments
+/// - `ty`: The [`Type`] to check.
+///
+/// # Returns
+/// True if `Self` occurs in `ty` (e.g., `Option<Self>`), or false if it doesn't (e.g.,
+/// `Self::Foo`).
+pub(crate) fn mentions_self(ty: &Type) -> bool {
+    let mut finder = SelfFinder { found: false };
+    finder.visit_type_mut(&mut ty.clone());
+    finder.found
+}
+
+
+/// Checks whether the given return type is `()`.
+///
+/// # Arguments
+/// - `output`: The [`ReturnType`] to check.
+///
+/// # Returns
+/// True if the method returns nothing (or `()` explicitly), or false otherwise.
+#[inline]
+pub(crate) fn returns_unit(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => true,
+        ReturnType::Type(_, ty) => matches!(&**ty, Type::Tuple(t) if t.elems.is_empty()),
+    }
+}
+
+
+/// Checks whether the given return type is exactly `Self`.
+///
+/// # Arguments
+/// - `output`: The [`ReturnType`] to check.
+///
+/// # Returns
+/// True if it's `-> Self`, or false otherwise.
+#[inline]
+pub(crate) fn returns_self(output: &ReturnType) -> bool { matches!(output, ReturnType::Type(_, ty) if is_self(ty)) }
+
+
+/// Finds a name for new generic parameters that doesn't clash with any name used in a trait.
+///
+/// Next to the trait's own generics (and those of its items), this avoids any other name that
+/// occurs in it, since a parameter would shadow e.g. a type of the same name used in a signature.
+///
+/// # Arguments
+/// - `def`: The [`ItemTrait`] whose names the new ones can't clash with.
+/// - `base`: The preferred name, e.g., `T`.
+///
+/// # Returns
+/// `base` with as many underscores appended as needed for no name in the trait to be like it, even
+/// when followed by a number. So it can also be numbered (e.g., `T0`, `T1`, ...).
+pub(crate) fn free_ident(def: &ItemTrait, base: &str) -> Ident {
+    // Collect every name in the trait
+    let mut names: HashSet<String> = HashSet::new();
+    let mut todo: Vec<TokenStream2> = vec![def.to_token_stream()];
+    while let Some(tokens) = todo.pop() {
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => {
+                    names.insert(ident.to_string());
+                },
+                TokenTree::Group(group) => todo.push(group.stream()),
+                TokenTree::Punct(_) | TokenTree::Literal(_) => {},
+            }
+        }
+    }
+
+    // Then find the first that isn't one of them
+    let mut name: String = base.to_string();
+    while names.iter().any(|ident| ident.strip_prefix(name.as_str()).is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit()))) {
+        name.push('_');
+    }
+    Ident::new(&name, Span::call_site())
+}
+
+
+/// Collects the arguments that a forwarded method passes on to the original one.
+///
+/// # Arguments
+/// - `sig`: The [`Signature`] of the method.
+///
+/// # Returns
+/// The patterns of all arguments except `self`.
+pub(crate) fn passing_args(sig: &Signature) -> Punctuated<Pat, Token![,]> {
+    sig.inputs
+        .iter()
+        .filter_map(|a| match a {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(t) => Some((*t.pat).clone()),
+        })
+        .collect()
+}
+
+
+/// Turns generic parameters into the generic arguments that refer to them.
+///
+/// # Arguments
+/// - `generics`: The [`Generics`] to refer to.
+///
+/// # Returns
+/// The arguments, e.g., `'a`, `T` and `N` for `<'a, T: Clone, const N: usize>`.
+pub(crate) fn generic_args(generics: &Generics) -> Vec<TokenStream2> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(l) => l.lifetime.to_token_stream(),
+            GenericParam::Type(t) => t.ident.to_token_stream(),
+            GenericParam::Const(c) => c.ident.to_token_stream(),
+        })
+        .collect()
+}
+
+
+/// Removes `mut` from a receiver taking `self` by value, since a generated method that moves it
+/// doesn't need it.
+///
+/// # Arguments
+/// - `sig`: The [`Signature`] of the generated method.
+pub(crate) fn strip_owned_mut(sig: &mut Signature) {
+    if let Some(FnArg::Receiver(r)) = sig.inputs.first_mut() {
+        if r.reference.is_none() {
+            r.mutability = None;
+        }
+    }
+}
+
+
+/// Writes the body of a generated method, which calls the method it forwards to in an `unsafe`
+/// block if it's `unsafe`.
+///
+/// Only the `call` is put in that block. The `lets` before it may contain expressions given by
+/// the user (e.g., accessors of custom types), which shouldn't silently be allowed to do unsafe
+/// operations.
+///
+/// # Arguments
+/// - `sig`: The [`Signature`] of the generated method.
+/// - `brace_token`: The [`Brace`]s to put around the body.
+/// - `lets`: Any statements to run before the call (e.g., binding the receiver).
+/// - `call`: The expression calling the forwarded method(s).
+/// - `tokens`: The [`TokenStream2`] to write the body to.
+pub(crate) fn body_to_tokens(sig: &Signature, brace_token: Brace, lets: TokenStream2, call: TokenStream2, tokens: &mut TokenStream2) {
+    let unsafety: &Option<Token![unsafe]> = &sig.unsafety;
+    brace_token.surround(tokens, |tokens| {
+        tokens.extend(lets);
+        if unsafety.is_some() {
+            tokens.extend(quote! { #unsafety { #call } });
+        } else {
+            call.to_tokens(tokens);
+        }
+    });
+}
+
+
+/// Parses a comma-separated list of where-predicates.
+///
+/// This stops as soon as the next comma is followed by something that isn't a predicate, so it can
+/// be used in the middle of other comma-separated things.
+///
+/// # Arguments
+/// - `input`: The [`ParseStream`] to parse from. Should be right after the `where`.
+///
+/// # Returns
+/// The parsed [`WherePredicate`]s.
+///
+/// # Errors
+/// This function errors if there wasn't at least one predicate to parse.
+fn parse_where_predicates(input: ParseStream) -> syn::Result<Vec<WherePredicate>> {
+    let mut preds: Vec<WherePredicate> = vec![input.parse()?];
+    while input.peek(Token![,]) {
+        let fork = input.fork();
+        fork.parse::<Token![,]>()?;
+        if fork.parse::<WherePredicate>().is_err() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+        preds.push(input.parse()?);
+    }
+    Ok(preds)
+}
+
+
+/// Parses the name of a [`Group`], if the input contains one.
+///
+/// Only parses anything if the next token is a group name that is followed by either a comma or
+/// the end of the input, as it would otherwise be a type.
+///
+/// # Arguments
+/// - `input`: The [`ParseStream`] to parse from.
+///
+/// # Returns
+/// The parsed [`Group`], or [`None`] if the next thing isn't one.
+///
+/// # Errors
+/// This function errors if the parsing failed.
+fn parse_group(input: ParseStream) -> syn::Result<Option<Group>> {
+    let fork = input.fork();
+    let Ok(name) = fork.parse::<Ident>() else { return Ok(None) };
+    if !fork.is_empty() && !fork.peek(Token![,]) {
+        return Ok(None);
+    }
+    let Some(group) = Group::from_ident(&name) else { return Ok(None) };
+    input.parse::<Ident>()?;
+    Ok(Some(group))
+}
+
+
 
 /***** VISITORS *****/
+/// Visitor that normalizes types (see [`normalize_type()`]).
+struct TypeNormalizer;
+impl VisitMut for TypeNormalizer {
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        visit_mut::visit_path_mut(self, node);
+
+        // Resolve the path to the full one in `std`
+        node.leading_colon = None;
+        if node.segments.len() == 1 {
+            let name: &Ident = &node.segments[0].ident;
+            if let Some((_, module)) = STD_TYPES.iter().find(|(ty, _)| name == ty) {
+                let module = Ident::new(module, name.span());
+                let std = Ident::new("std", name.span());
+                node.segments.insert(0, PathSegment::from(module));
+                node.segments.insert(0, PathSegment::from(std));
+            }
+        } else if node.segments[0].ident == "alloc" || node.segments[0].ident == "core" {
+            node.segments[0].ident = Ident::new("std", node.segments[0].ident.span());
+        }
+    }
+
+    fn visit_angle_bracketed_generic_arguments_mut(&mut self, node: &mut AngleBracketedGenericArguments) {
+        node.args = std::mem::take(&mut node.args).into_iter().filter(|arg| !matches!(arg, GenericArgument::Lifetime(_))).collect();
+        visit_mut::visit_angle_bracketed_generic_arguments_mut(self, node);
+    }
+
+    fn visit_type_reference_mut(&mut self, node: &mut TypeReference) {
+        node.lifetime = None;
+        visit_mut::visit_type_reference_mut(self, node);
+    }
+}
+
+/// Visitor that finds if a type mentions `Self` directly.
+struct SelfFinder {
+    found: bool,
+}
+impl VisitMut for SelfFinder {
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        if is_self(node) {
+            self.found = true;
+            return;
+        }
+        if let Type::Path(ty) = node {
+            // `<Self as Foo>::Bar` is fine too, so only check the generics in that case
+            if let Some(qself) = &ty.qself {
+                if is_self(&qself.ty) {
+                    visit_mut::visit_path_mut(self, &mut ty.path);
+                    return;
+                }
+            }
+        }
+
+        // Any other type is handled with the default impl!
+        visit_mut::visit_type_mut(self, node)
+    }
+}
+
 /// Visitor that resolves all self types with the given one.
 struct SelfResolver {
     ident: Ident,
@@ -704,15 +663,56 @@ impl VisitMut for SelfResolver {
     }
 }
 
+/// Visitor that replaces the generic `T` with the given type.
+struct GenericResolver {
+    ident: Ident,
+    ty:    Type,
+}
+impl VisitMut for GenericResolver {
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        // If the type is the generic one, then replace it and done
+        if matches!(node, Type::Path(TypePath { qself: None, path }) if path.is_ident(&self.ident)) {
+            *node = self.ty.clone();
+        } else {
+            // Any other type is handled with the default impl!
+            visit_mut::visit_type_mut(self, node)
+        }
+    }
+}
+
+/// Visitor that moves paths in `std` to the given [`Library`] (e.g., `::std::boxed::Box` to
+/// `::alloc::boxed::Box`).
+struct LibraryResolver {
+    library:   Library,
+    /// Set to false if any of the paths is not available in the library.
+    available: bool,
+}
+impl VisitMut for LibraryResolver {
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        visit_mut::visit_path_mut(self, node);
+
+        // Only consider full paths into `std`
+        if node.leading_colon.is_none() || node.segments.len() < 3 || node.segments[0].ident != "std" {
+            return;
+        }
+        let library: Library = Library::of(&node.segments[1].ident, &node.segments[2].ident);
+        if library > self.library {
+            self.available = false;
+            return;
+        }
+        node.segments[0].ident = Ident::new(library.name(), node.segments[0].ident.span());
+    }
+}
+
 /// Visitor that resolves all inferred types with the given one.
 struct InferResolver {
-    ty: TypePath,
+    ty: Type,
 }
 impl VisitMut for InferResolver {
     fn visit_type_mut(&mut self, node: &mut Type) {
         // If the type is the inferred one, then replace it and done
         if matches!(node, Type::Infer(_)) {
-            *node = Type::Path(self.ty.clone());
+            *node = self.ty.clone();
         } else {
             // Any other type is handled with the default impl!
             visit_mut::visit_type_mut(self, node)
@@ -725,34 +725,748 @@ impl VisitMut for InferResolver {
 
 
 /***** GENERATOR *****/
+/// Defines named groups of types that can be given to `impl` and `unimpl` in one go.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Group {
+    /// `&'a _` and `&'a mut _`.
+    Refs,
+    /// `Box<_>`, `Rc<_>` and `Arc<_>`.
+    Smart,
+    /// `Ref<'a, _>` and `RefMut<'a, _>`.
+    Cells,
+    /// The guards of `std::sync`'s `Mutex` and `RwLock`.
+    StdLocks,
+    /// The guards of `parking_lot`'s `Mutex` and `RwLock`.
+    ParkingLot,
+    /// The guards of `lock_api`'s `Mutex`, `RwLock` and `ReentrantMutex`, for any raw lock.
+    LockApi,
+    /// The guards of `tokio`'s `Mutex` and `RwLock`.
+    Tokio,
+    /// `triomphe`'s `Arc`, `ArcBorrow` and `UniqueArc`.
+    Triomphe,
+    /// `servo_arc`'s `Arc`, `ArcBorrow` and `UniqueArc`.
+    ServoArc,
+    /// `archery`'s `SharedPointer`, for any kind of pointer.
+    Archery,
+    /// `Cow<'a, _>`.
+    Cow,
+    /// `ManuallyDrop<_>`.
+    ManuallyDrop,
+    /// `AssertUnwindSafe<_>`.
+    AssertUnwindSafe,
+    /// `LazyLock<_, F>` and `LazyCell<_, F>`.
+    Lazy,
+    /// `ReentrantLockGuard<'a, _>`.
+    ReentrantLock,
+    /// `Pin<Box<_>>`.
+    PinBox,
+    /// `Pin<P>` for any `P` that dereferences to something implementing the trait.
+    Pin,
+}
+impl Group {
+    /// All the groups there are.
+    const ALL: [Self; 17] = [
+        Self::Refs,
+        Self::Smart,
+        Self::Cells,
+        Self::StdLocks,
+        Self::ParkingLot,
+        Self::LockApi,
+        Self::Tokio,
+        Self::Triomphe,
+        Self::ServoArc,
+        Self::Archery,
+        Self::Cow,
+        Self::ManuallyDrop,
+        Self::AssertUnwindSafe,
+        Self::Lazy,
+        Self::ReentrantLock,
+        Self::PinBox,
+        Self::Pin,
+    ];
+
+    /// Finds the group with the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The [`Ident`] that names the group (e.g., `refs`).
+    ///
+    /// # Returns
+    /// The matching [`Group`], or [`None`] if there is no group with that name.
+    fn from_ident(name: &Ident) -> Option<Self> {
+        Self::ALL.into_iter().find(|group| name == group.name())
+    }
+
+    /// Returns the name of this group as the user writes it.
+    #[inline]
+    fn name(self) -> &'static str {
+        match self {
+            Self::Refs => "refs",
+            Self::Smart => "smart",
+            Self::Cells => "cells",
+            Self::StdLocks => "std_locks",
+            Self::ParkingLot => "parking_lot",
+            Self::LockApi => "lock_api",
+            Self::Tokio => "tokio",
+            Self::Triomphe => "triomphe",
+            Self::ServoArc => "servo_arc",
+            Self::Archery => "archery",
+            Self::Cow => "cow",
+            Self::ManuallyDrop => "manually_drop",
+            Self::AssertUnwindSafe => "assert_unwind_safe",
+            Self::Lazy => "lazy",
+            Self::ReentrantLock => "reentrant_lock",
+            Self::PinBox => "pin_box",
+            Self::Pin => "pin",
+        }
+    }
+
+    /// Checks whether this group is part of the default types (i.e., `*`).
+    #[inline]
+    fn is_default(self) -> bool { matches!(self, Self::Refs | Self::Smart | Self::Cells | Self::StdLocks) }
+
+    /// Returns the types in this group.
+    ///
+    /// # Returns
+    /// A list of [`TypeToImpl`]s that describe the implementations to generate for this group.
+    ///
+    /// # Errors
+    /// This function errors if the group's types live in a crate that the invoking crate doesn't
+    /// depend on.
+    fn types(self) -> Result<Vec<TypeToImpl>, String> {
+        Ok(match self {
+            Self::Refs => vec![
+                TypeToImpl::builtin(parse_quote! { &'a _ }, false, Ownership::Borrowed, true),
+                TypeToImpl::builtin(parse_quote! { &'a mut _ }, true, Ownership::Borrowed, true),
+            ],
+            Self::Smart => vec![
+                TypeToImpl::builtin(parse_quote! { ::std::boxed::Box<_> }, true, Ownership::Unique, false),
+                TypeToImpl::builtin(parse_quote! { ::std::rc::Rc<_> }, false, Ownership::Shared, false),
+                TypeToImpl::builtin(parse_quote! { ::std::sync::Arc<_> }, false, Ownership::Shared, false),
+            ],
+            Self::Cells => vec![
+                TypeToImpl::builtin(parse_quote! { ::std::cell::Ref<'a, _> }, false, Ownership::Borrowed, true),
+                TypeToImpl::builtin(parse_quote! { ::std::cell::RefMut<'a, _> }, true, Ownership::Borrowed, true),
+            ],
+            Self::StdLocks => vec![
+                TypeToImpl::builtin(parse_quote! { ::std::sync::MutexGuard<'a, _> }, true, Ownership::Borrowed, true),
+                TypeToImpl::builtin(parse_quote! { ::std::sync::RwLockReadGuard<'a, _> }, false, Ownership::Borrowed, true),
+                TypeToImpl::builtin(parse_quote! { ::std::sync::RwLockWriteGuard<'a, _> }, true, Ownership::Borrowed, true),
+            ],
+            Self::ParkingLot => {
+                let krate: Path = dependency_path("parking_lot")?;
+                vec![
+                    TypeToImpl::builtin(parse_quote! { #krate::MutexGuard<'a, _> }, true, Ownership::Borrowed, true),
+                    TypeToImpl::builtin(parse_quote! { #krate::RwLockReadGuard<'a, _> }, false, Ownership::Borrowed, true),
+                    TypeToImpl::builtin(parse_quote! { #krate::RwLockWriteGuard<'a, _> }, true, Ownership::Borrowed, true),
+                ]
+            },
+            Self::LockApi => {
+                let krate: Path = dependency_path("lock_api")?;
+                let guard = |ty: Type, mutable: bool, raw: Ident| -> TypeToImpl {
+                    TypeToImpl {
+                        generics: Some(parse_quote! { <'a, R> }),
+                        bounds: vec![parse_quote! { R: #krate::#raw }],
+                        ..TypeToImpl::builtin(ty, mutable, Ownership::Borrowed, false)
+                    }
+                };
+                let reentrant_guard = |ty: Type| -> TypeToImpl {
+                    TypeToImpl {
+                        generics: Some(parse_quote! { <'a, R, G> }),
+                        bounds: vec![parse_quote! { R: #krate::RawMutex }, parse_quote! { G: #krate::GetThreadId }],
+                        ..TypeToImpl::builtin(ty, false, Ownership::Borrowed, false)
+                    }
+                };
+                let raw_mutex = Ident::new("RawMutex", Span::call_site());
+                let raw_rw_lock = Ident::new("RawRwLock", Span::call_site());
+                vec![
+                    guard(parse_quote! { #krate::MutexGuard<'a, R, _> }, true, raw_mutex.clone()),
+                    guard(parse_quote! { #krate::MappedMutexGuard<'a, R, _> }, true, raw_mutex),
+                    guard(parse_quote! { #krate::RwLockReadGuard<'a, R, _> }, false, raw_rw_lock.clone()),
+                    guard(parse_quote! { #krate::RwLockWriteGuard<'a, R, _> }, true, raw_rw_lock.clone()),
+                    guard(parse_quote! { #krate::RwLockUpgradableReadGuard<'a, R, _> }, false, Ident::new("RawRwLockUpgrade", Span::call_site())),
+                    guard(parse_quote! { #krate::MappedRwLockReadGuard<'a, R, _> }, false, raw_rw_lock.clone()),
+                    guard(parse_quote! { #krate::MappedRwLockWriteGuard<'a, R, _> }, true, raw_rw_lock),
+                    reentrant_guard(parse_quote! { #krate::ReentrantMutexGuard<'a, R, G, _> }),
+                    reentrant_guard(parse_quote! { #krate::MappedReentrantMutexGuard<'a, R, G, _> }),
+                ]
+            },
+            Self::Tokio => {
+                let krate: Path = dependency_path("tokio")?;
+                // The owned, mapped guards also carry the type they were mapped from
+                let mapped_owned_guard = |ty: Type, mutable: bool| -> TypeToImpl {
+                    TypeToImpl {
+                        generics: Some(parse_quote! { <O: ?::std::marker::Sized> }),
+                        ..TypeToImpl::builtin(ty, mutable, Ownership::Borrowed, false)
+                    }
+                };
+                vec![
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::MutexGuard<'a, _> }, true, Ownership::Borrowed, true),
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::OwnedMutexGuard<_> }, true, Ownership::Borrowed, false),
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::MappedMutexGuard<'a, _> }, true, Ownership::Borrowed, true),
+                    mapped_owned_guard(parse_quote! { #krate::sync::OwnedMappedMutexGuard<O, _> }, true),
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::RwLockReadGuard<'a, _> }, false, Ownership::Borrowed, true),
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::RwLockWriteGuard<'a, _> }, true, Ownership::Borrowed, true),
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::RwLockMappedWriteGuard<'a, _> }, true, Ownership::Borrowed, true),
+                    mapped_owned_guard(parse_quote! { #krate::sync::OwnedRwLockReadGuard<O, _> }, false),
+                    TypeToImpl::builtin(parse_quote! { #krate::sync::OwnedRwLockWriteGuard<_> }, true, Ownership::Borrowed, false),
+                    mapped_owned_guard(parse_quote! { #krate::sync::OwnedRwLockMappedWriteGuard<O, _> }, true),
+                ]
+            },
+            Self::Triomphe => {
+                let krate: Path = dependency_path("triomphe")?;
+                vec![
+                    TypeToImpl::builtin(parse_quote! { #krate::Arc<_> }, false, Ownership::Shared, false),
+                    TypeToImpl::builtin(parse_quote! { #krate::ArcBorrow<'a, _> }, false, Ownership::Borrowed, true),
+                    TypeToImpl {
+                        accessors: Accessors { owned: Some(parse_quote! { #krate::UniqueArc::into_inner(self) }), ..Default::default() },
+                        ..TypeToImpl::builtin(parse_quote! { #krate::UniqueArc<_> }, true, Ownership::Unique, false)
+                    },
+                ]
+            },
+            Self::ServoArc => {
+                // Only `Arc` supports unsized types, and none can be moved out of
+                let krate: Path = dependency_path("servo_arc")?;
+                vec![
+                    TypeToImpl::builtin(parse_quote! { #krate::Arc<_> }, false, Ownership::Borrowed, false),
+                    TypeToImpl {
+                        maybe_unsized: false,
+                        ..TypeToImpl::builtin(parse_quote! { #krate::ArcBorrow<'a, _> }, false, Ownership::Borrowed, true)
+                    },
+                    TypeToImpl {
+                        maybe_unsized: false,
+                        ..TypeToImpl::builtin(parse_quote! { #krate::UniqueArc<_> }, true, Ownership::Borrowed, false)
+                    },
+                ]
+            },
+            Self::Archery => {
+                // `archery` only supports sized types, and has no `unwrap_or_clone()` to move out
+                let krate: Path = dependency_path("archery")?;
+                vec![TypeToImpl {
+                    maybe_unsized: false,
+                    generics: Some(parse_quote! { <K> }),
+                    bounds: vec![parse_quote! { K: #krate::SharedPointerKind }],
+                    ..TypeToImpl::builtin(parse_quote! { #krate::SharedPointer<_, K> }, false, Ownership::Borrowed, false)
+                }]
+            },
+            Self::Cow => vec![TypeToImpl {
+                bounds: vec![parse_quote! { _: ::std::borrow::ToOwned }],
+                ..TypeToImpl::builtin(parse_quote! { ::std::borrow::Cow<'a, _> }, false, Ownership::Borrowed, true)
+            }],
+            Self::ManuallyDrop => vec![TypeToImpl {
+                accessors: Accessors { owned: Some(parse_quote! { ::std::mem::ManuallyDrop::into_inner(self) }), ..Default::default() },
+                ..TypeToImpl::builtin(parse_quote! { ::std::mem::ManuallyDrop<_> }, true, Ownership::Unique, false)
+            }],
+            Self::AssertUnwindSafe => vec![TypeToImpl {
+                maybe_unsized: false,
+                accessors: Accessors { owned: Some(parse_quote! { self.0 }), ..Default::default() },
+                ..TypeToImpl::builtin(parse_quote! { ::std::panic::AssertUnwindSafe<_> }, true, Ownership::Unique, false)
+            }],
+            Self::Lazy => vec![
+                TypeToImpl {
+                    maybe_unsized: false,
+                    generics: Some(parse_quote! { <F> }),
+                    bounds: vec![parse_quote! { F: ::std::ops::FnOnce() -> _ }],
+                    ..TypeToImpl::builtin(parse_quote! { ::std::sync::LazyLock<_, F> }, false, Ownership::Borrowed, false)
+                },
+                TypeToImpl {
+                    maybe_unsized: false,
+                    generics: Some(parse_quote! { <F> }),
+                    bounds: vec![parse_quote! { F: ::std::ops::FnOnce() -> _ }],
+                    ..TypeToImpl::builtin(parse_quote! { ::std::cell::LazyCell<_, F> }, false, Ownership::Borrowed, false)
+                },
+            ],
+            Self::ReentrantLock => {
+                vec![TypeToImpl::builtin(parse_quote! { ::std::sync::ReentrantLockGuard<'a, _> }, false, Ownership::Borrowed, true)]
+            },
+            Self::PinBox => vec![TypeToImpl {
+                mut_bounds: vec![parse_quote! { _: ::std::marker::Unpin }],
+                ..TypeToImpl::builtin(parse_quote! { ::std::pin::Pin<::std::boxed::Box<_>> }, true, Ownership::Borrowed, false)
+            }],
+            Self::Pin => vec![TypeToImpl {
+                generics: Some(parse_quote! { <P> }),
+                bounds: vec![parse_quote! { P: ::std::ops::Deref }],
+                mut_bounds: vec![parse_quote! { P: ::std::ops::DerefMut }, parse_quote! { _: ::std::marker::Unpin }],
+                target: Some(parse_quote! { <P as ::std::ops::Deref>::Target }),
+                ..TypeToImpl::builtin(parse_quote! { ::std::pin::Pin<P> }, true, Ownership::Borrowed, false)
+            }],
+        })
+    }
+}
+
+/// Describes how a pointer type owns its `T`, which determines if `T` can be moved out of it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Ownership {
+    /// The type only borrows `T`, so it can never give it by value (e.g., `&T`).
+    Borrowed,
+    /// The type uniquely owns `T`, so it can be moved out with `*self` (e.g., `Box<T>`).
+    Unique,
+    /// The type shares ownership of `T`, so it has to be unwrapped first (e.g., `Rc<T>`).
+    Shared,
+}
+
+/// Describes what to do with methods that have a default implementation.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) enum Defaults {
+    /// Forward them to `T`, like any other method.
+    Forward,
+    /// Don't implement them, so they inherit the default implementation.
+    #[default]
+    Inherit,
+}
+
+/// Describes which of the standard libraries the generated impls may refer to.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Library {
+    /// Only `core`, for `#![no_std]` crates without an allocator.
+    Core,
+    /// Both `core` and `alloc`, for `#![no_std]` crates with an allocator.
+    Alloc,
+    /// All of `std`.
+    #[default]
+    Std,
+}
+impl Library {
+    /// Finds the library that provides some type of `std`.
+    ///
+    /// # Arguments
+    /// - `module`: The module in `std` where the type lives (e.g., `boxed`).
+    /// - `name`: The name of the type itself (e.g., `Box`).
+    ///
+    /// # Returns
+    /// The smallest [`Library`] that provides the type.
+    fn of(module: &Ident, name: &Ident) -> Self {
+        if module == "cell" || module == "clone" || module == "marker" || module == "mem" || module == "ops" || module == "panic" || module == "pin" {
+            Self::Core
+        } else if module == "borrow" || module == "boxed" || module == "rc" || (module == "sync" && (name == "Arc" || name == "Weak")) {
+            Self::Alloc
+        } else {
+            Self::Std
+        }
+    }
+
+    /// Returns the name of the crate implementing this library.
+    #[inline]
+    fn name(self) -> &'static str {
+        match self {
+            Self::Core => "core",
+            Self::Alloc => "alloc",
+            Self::Std => "std",
+        }
+    }
+}
+
+/// Describes how to obtain an owned `T` from a pointer type with [`Ownership::Shared`] ownership.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum OwnedStrategy {
+    /// Use `try_unwrap()`, and panic if the pointer is not unique.
+    Unwrap,
+    /// Use `unwrap_or_clone()`, which requires `T: Clone`.
+    Clone,
+}
+
+/// Describes the kinds of `self` that methods can take.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum ReceiverKind {
+    /// `&self`
+    Ref,
+    /// `&mut self`
+    Mut,
+    /// `self`
+    Owned,
+    /// `self: Box<Self>`
+    Box,
+    /// `self: Rc<Self>`
+    Rc,
+    /// `self: Arc<Self>`
+    Arc,
+    /// `self: Pin<&Self>`
+    PinRef,
+    /// `self: Pin<&mut Self>`
+    PinMut,
+}
+impl Display for ReceiverKind {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::Ref => write!(f, "&self"),
+            Self::Mut => write!(f, "&mut self"),
+            Self::Owned => write!(f, "self"),
+            Self::Box => write!(f, "self: Box<Self>"),
+            Self::Rc => write!(f, "self: Rc<Self>"),
+            Self::Arc => write!(f, "self: Arc<Self>"),
+            Self::PinRef => write!(f, "self: Pin<&Self>"),
+            Self::PinMut => write!(f, "self: Pin<&mut Self>"),
+        }
+    }
+}
+impl ReceiverKind {
+    /// Analyses a [`Receiver`] to find which kind it is.
+    ///
+    /// # Arguments
+    /// - `receiver`: The [`Receiver`] to analyse.
+    ///
+    /// # Returns
+    /// A new ReceiverKind describing it.
+    ///
+    /// # Errors
+    /// This function errors if the receiver has an explicit type that we don't support.
+    fn parse(receiver: &Receiver) -> syn::Result<Self> {
+        if receiver.colon_token.is_none() {
+            return Ok(match (&receiver.reference, &receiver.mutability) {
+                (Some(_), Some(_)) => Self::Mut,
+                (Some(_), None) => Self::Ref,
+                (None, _) => Self::Owned,
+            });
+        }
+
+        // Else, see if the type is one we support
+        match &*receiver.ty {
+            ty if is_self(ty) => Ok(Self::Owned),
+            Type::Reference(TypeReference { mutability, elem, .. }) if is_self(elem) => Ok(if mutability.is_some() { Self::Mut } else { Self::Ref }),
+            Type::Path(TypePath { qself: None, path }) => match single_type_arg(path) {
+                Some(arg) if is_self(arg) && is_std_path(path, "boxed", "Box") => Ok(Self::Box),
+                Some(arg) if is_self(arg) && is_std_path(path, "rc", "Rc") => Ok(Self::Rc),
+                Some(arg) if is_self(arg) && is_std_path(path, "sync", "Arc") => Ok(Self::Arc),
+                Some(Type::Reference(TypeReference { mutability, elem, .. })) if is_self(elem) && is_std_path(path, "pin", "Pin") => {
+                    Ok(if mutability.is_some() { Self::PinMut } else { Self::PinRef })
+                },
+                _ => Err(Error::new(path.span(), "Receiver type is not supported by `#[pointer_impls]`")),
+            },
+            ty => Err(Error::new(ty.span(), "Receiver type is not supported by `#[pointer_impls]`")),
+        }
+    }
+}
+
+/// Describes how to pass `self` on to `T` for a particular type and receiver.
+pub(crate) struct Access {
+    /// The expression that turns `self` into the receiver expected by `T`.
+    expr:   TokenStream2,
+    /// Whether this requires `T` to be sized.
+    sized:  bool,
+    /// Any additional bounds this requires.
+    bounds: Vec<WherePredicate>,
+}
+
+/// Describes the custom expressions that map `self` to `T` for every kind of receiver.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) struct Accessors {
+    /// The expression used for `&self` (and `self: &Self`).
+    pub(crate) by_ref: Option<Expr>,
+    /// The expression used for `&mut self` (and `self: &mut Self`).
+    pub(crate) by_mut: Option<Expr>,
+    /// The expression used for `self` (and `self: Self`).
+    pub(crate) owned:  Option<Expr>,
+}
+impl Accessors {
+    /// Checks whether any custom expression was given.
+    ///
+    /// # Returns
+    /// True if at least one of the accessors is given, or false otherwise.
+    #[inline]
+    fn is_custom(&self) -> bool { self.by_ref.is_some() || self.by_mut.is_some() || self.owned.is_some() }
+}
+impl Parse for Accessors {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Parse it as either a single expression for references or a list of them
+        if input.parse::<Token![=]>().is_ok() {
+            let expr: Expr = input.parse()?;
+            return Ok(Self { by_ref: Some(expr.clone()), by_mut: Some(expr), owned: None });
+        }
+        let content;
+        braced!(content in input);
+        let mut accessors = Self::default();
+        while !content.is_empty() {
+            // Parse the kind of receiver, then the expression
+            let kind: Ident = content.call(Ident::parse_any)?;
+            let accessor: &mut Option<Expr> = if kind == "ref" {
+                &mut accessors.by_ref
+            } else if kind == "mut" {
+                &mut accessors.by_mut
+            } else if kind == "owned" {
+                &mut accessors.owned
+            } else {
+                return Err(Error::new(kind.span(), "Expected either 'ref', 'mut' or 'owned'"));
+            };
+            if accessor.is_some() {
+                return Err(Error::new(kind.span(), format!("Duplicate accessor '{kind}'")));
+            }
+            content.parse::<Token![=]>()?;
+            *accessor = Some(content.parse()?);
+
+            // Parse the separator, if any
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+        Ok(accessors)
+    }
+}
+
 /// Specifies that which we need to know about every to-be-generated type.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-struct TypeToImpl {
+pub(crate) struct TypeToImpl {
     /// The type to implement for.
-    ty: Type,
+    pub(crate) ty: Type,
     /// Whether this type is interior mutable or not.
-    mutable: bool,
+    pub(crate) mutable: bool,
+    /// Whether this type can wrap an unsized `T`.
+    pub(crate) maybe_unsized: bool,
+    /// How this type owns `T`.
+    pub(crate) ownership: Ownership,
     /// The generics to add for this type.
-    generics: Option<Generics>,
-    /// The optional closure that maps `self` to whatever.
-    closure: Option<Expr>,
+    pub(crate) generics: Option<Generics>,
+    /// The optional expressions that map `self` to whatever.
+    pub(crate) accessors: Accessors,
+    /// Any additional bounds to add to the impl for this type.
+    pub(crate) bounds: Vec<WherePredicate>,
+    /// Any additional bounds to add to the impl if methods need mutable access to `T`.
+    pub(crate) mut_bounds: Vec<WherePredicate>,
+    /// The type implementing the trait if it isn't `T` itself, but some projection of this type's
+    /// generics (e.g., `<P as Deref>::Target` for `Pin<P>`).
+    pub(crate) target: Option<Type>,
+}
+impl TypeToImpl {
+    /// Constructor for one of the types we know out-of-the-box.
+    ///
+    /// # Arguments
+    /// - `ty`: The [`Type`] to implement for, using `_` for `T`.
+    /// - `mutable`: Whether the type gives mutable access to `T`.
+    /// - `ownership`: How the type owns `T`.
+    /// - `lifetime`: Whether the type uses a lifetime `'a` that should be added to the impl.
+    ///
+    /// # Returns
+    /// A new TypeToImpl that can wrap unsized types.
+    fn builtin(ty: Type, mutable: bool, ownership: Ownership, lifetime: bool) -> Self {
+        Self {
+            ty,
+            mutable,
+            maybe_unsized: true,
+            ownership,
+            generics: if lifetime { Some(parse_quote! { <'a> }) } else { None },
+            accessors: Accessors::default(),
+            bounds: Vec::new(),
+            mut_bounds: Vec::new(),
+            target: None,
+        }
+    }
+
+    /// Returns the path of this type without any generic arguments, if it's a path at all.
+    ///
+    /// # Returns
+    /// A [`Path`] that can be used to refer to the type's associated functions (e.g., `::std::rc::Rc`).
+    fn path(&self) -> Option<Path> {
+        let Type::Path(TypePath { qself: None, path }) = &self.ty else { return None };
+        let mut path: Path = path.clone();
+        if let Some(last) = path.segments.last_mut() {
+            last.arguments = PathArguments::None;
+        }
+        Some(path)
+    }
+
+    /// Moves the paths in this type to the given standard library.
+    ///
+    /// # Arguments
+    /// - `library`: The [`Library`] to use instead of `std`.
+    ///
+    /// # Returns
+    /// False if this type (or any of its bounds) isn't available in `library`, or true otherwise.
+    fn resolve_library(&mut self, library: Library) -> bool {
+        let mut resolver = LibraryResolver { library, available: true };
+        resolver.visit_type_mut(&mut self.ty);
+        if let Some(generics) = &mut self.generics {
+            resolver.visit_generics_mut(generics);
+        }
+        for expr in [&mut self.accessors.by_ref, &mut self.accessors.by_mut, &mut self.accessors.owned].into_iter().flatten() {
+            resolver.visit_expr_mut(expr);
+        }
+        for bound in self.bounds.iter_mut().chain(self.mut_bounds.iter_mut()) {
+            resolver.visit_where_predicate_mut(bound);
+        }
+        if let Some(target) = &mut self.target {
+            resolver.visit_type_mut(target);
+        }
+        resolver.available
+    }
+
+    /// Checks whether this type is the one the user referred to.
+    ///
+    /// # Arguments
+    /// - `ty`: The [`Type`] given by the user (e.g., `::std::rc::Rc<_>`).
+    ///
+    /// # Returns
+    /// True if `ty` refers to this type, or false otherwise.
+    ///
+    /// This is not a structural comparison, but a semantic one (see [`normalize_type()`]). As
+    /// such, `Box<_>` matches `::std::boxed::Box<_>` and `Ref<_>` matches
+    /// `::std::cell::Ref<'a, _>`.
+    #[inline]
+    fn matches(&self, ty: &Type) -> bool { normalize_type(&self.ty) == normalize_type(ty) }
+
+    /// Finds how to pass `self` of this type to `T` for a given receiver.
+    ///
+    /// # Arguments
+    /// - `receiver`: The [`ReceiverKind`] of the method we're forwarding.
+    /// - `owned`: The [`OwnedStrategy`] the user chose for shared pointers, if any.
+    /// - `t`: The `T` that we're forwarding to.
+    /// - `ty`: This type with `T` filled in.
+    ///
+    /// # Returns
+    /// An [`Access`] describing how to do so.
+    ///
+    /// # Errors
+    /// This function returns the reason why this type can't support the receiver if it can't.
+    pub(crate) fn access(&self, receiver: ReceiverKind, owned: Option<OwnedStrategy>, t: &Type, ty: &Type) -> Result<Access, String> {
+        let this = |accessor: &Option<Expr>| -> TokenStream2 {
+            match accessor {
+                Some(accessor) => accessor.to_token_stream(),
+                None => quote! { self },
+            }
+        };
+        let mut_bounds: Vec<WherePredicate> = self
+            .mut_bounds
+            .iter()
+            .map(|bound| {
+                let mut bound: WherePredicate = bound.clone();
+                InferResolver { ty: t.clone() }.visit_where_predicate_mut(&mut bound);
+                bound
+            })
+            .collect();
+        let path: Option<Path> = self.path();
+        let is = |module: &str, name: &str| -> bool {
+            !self.accessors.is_custom() && path.as_ref().map(|p| is_std_path(p, module, name)).unwrap_or(false)
+        };
+        match receiver {
+            ReceiverKind::Ref => Ok(Access { expr: this(&self.accessors.by_ref), sized: false, bounds: Vec::new() }),
+            ReceiverKind::Mut => {
+                if self.mutable {
+                    Ok(Access { expr: this(&self.accessors.by_mut), sized: false, bounds: mut_bounds })
+                } else {
+                    Err("it does not give mutable access to its contents (mark it as `mut` if it does)".into())
+                }
+            },
+            ReceiverKind::Owned if self.accessors.owned.is_some() => {
+                Ok(Access { expr: this(&self.accessors.owned), sized: true, bounds: Vec::new() })
+            },
+            ReceiverKind::Owned => match self.ownership {
+                Ownership::Borrowed => Err("it only borrows its contents".into()),
+                Ownership::Unique => Ok(Access { expr: quote! { *self }, sized: true, bounds: Vec::new() }),
+                Ownership::Shared => {
+                    let Some(path) = path else { return Err("it is not a path to a shared pointer".into()) };
+                    match owned {
+                        Some(OwnedStrategy::Unwrap) => {
+                            let msg: String = format!(
+                                "Cannot move out of a `{}` that has other references",
+                                path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default()
+                            );
+                            Ok(Access {
+                                expr:   quote! {
+                                    match #path::try_unwrap(self) {
+                                        ::core::result::Result::Ok(this) => this,
+                                        ::core::result::Result::Err(_) => ::core::panic!(#msg),
+                                    }
+                                },
+                                sized:  true,
+                                bounds: Vec::new(),
+                            })
+                        },
+                        Some(OwnedStrategy::Clone) => Ok(Access {
+                            expr:   quote! { #path::unwrap_or_clone(self) },
+                            sized:  true,
+                            bounds: vec![parse_quote! { #t: ::core::clone::Clone }],
+                        }),
+                        None => Err("it shares its contents (use `owned = unwrap` or `owned = clone` to move out of it anyway)".into()),
+                    }
+                },
+            },
+
+            // `Box<Box<T>>` can just be moved out of
+            ReceiverKind::Box if is("boxed", "Box") => Ok(Access { expr: quote! { *self }, sized: false, bounds: Vec::new() }),
+            // `Rc<Rc<T>>` and `Arc<Arc<T>>` can be cloned cheaply if they're shared
+            ReceiverKind::Rc if is("rc", "Rc") => Ok(Access { expr: quote! { #path::unwrap_or_clone(self) }, sized: false, bounds: Vec::new() }),
+            ReceiverKind::Arc if is("sync", "Arc") => Ok(Access { expr: quote! { #path::unwrap_or_clone(self) }, sized: false, bounds: Vec::new() }),
+            ReceiverKind::Box | ReceiverKind::Rc | ReceiverKind::Arc => {
+                Err(format!("it is not the same type as the receiver (`{}` can only be forwarded by that pointer itself)", receiver))
+            },
+
+            // Pins themselves can project pinned receivers to their contents directly
+            ReceiverKind::PinRef if is("pin", "Pin") => {
+                Ok(Access { expr: quote! { ::core::pin::Pin::as_ref(::core::pin::Pin::get_ref(self)) }, sized: false, bounds: Vec::new() })
+            },
+            ReceiverKind::PinMut if is("pin", "Pin") => {
+                let Type::Path(TypePath { path, .. }) = ty else { return Err("it is not a path to a pin".into()) };
+                let Some(ptr) = single_type_arg(path) else { return Err("it does not have exactly one pointer type to project through".into()) };
+                Ok(Access {
+                    expr:   quote! { ::core::pin::Pin::as_mut(::core::pin::Pin::get_mut(self)) },
+                    sized:  false,
+                    bounds: vec![parse_quote! { #ty: ::core::marker::Unpin }, parse_quote! { #ptr: ::core::ops::DerefMut }],
+                })
+            },
+
+            // Other pointers can be projected if `T` doesn't care about being pinned
+            ReceiverKind::PinRef if self.accessors.by_ref.is_some() => Err("it uses a custom expression to access its contents".into()),
+            ReceiverKind::PinMut if self.accessors.by_mut.is_some() => Err("it uses a custom expression to access its contents".into()),
+            ReceiverKind::PinRef => Ok(Access {
+                expr:   quote! { ::core::pin::Pin::new(&**::core::pin::Pin::get_ref(self)) },
+                sized:  false,
+                bounds: vec![parse_quote! { #t: ::core::marker::Unpin }],
+            }),
+            ReceiverKind::PinMut => {
+                if self.mutable {
+                    Ok(Access {
+                        expr:   quote! { ::core::pin::Pin::new(&mut **::core::pin::Pin::get_mut(self)) },
+                        sized:  false,
+                        bounds: [parse_quote! { #t: ::core::marker::Unpin }, parse_quote! { #ty: ::core::marker::Unpin }]
+                            .into_iter()
+                            .chain(mut_bounds)
+                            .collect(),
+                    })
+                } else {
+                    Err("it does not give mutable access to its contents (mark it as `mut` if it does)".into())
+                }
+            },
+        }
+    }
 }
 
 /// Specifies the attributes we're parsing from the attribute.
-struct Attributes {
+pub(crate) struct Attributes {
     /// The generic type to use in the impls
-    generic: Ident,
+    pub(crate) generic: Ident,
+    /// Whether the user forced `T` to be `?Sized` (true) or `Sized` (false). If omitted, it's
+    /// `?Sized` unless any of the trait's items require it to be sized.
+    maybe_unsized: Option<bool>,
+    /// What to do with methods with default implementations.
+    defaults: Defaults,
+    /// How to move out of shared pointers for methods taking `self`, if at all.
+    owned: Option<OwnedStrategy>,
+    /// Which standard library the impls may refer to.
+    library: Library,
+    /// Any additional bounds to add to all impls.
+    bounds: Vec<WherePredicate>,
     /// The list of types for which to generate the impls
-    types:   HashSet<TypeToImpl>,
+    pub(crate) types:   HashSet<TypeToImpl>,
+    /// The types in `types` the user explicitly asked for, which are an error if we can't do them.
+    /// Maps them to where they were given.
+    explicit: HashMap<Type, Span>,
 }
 impl Default for Attributes {
     #[inline]
-    fn default() -> Self { Self { generic: Ident::new("T", Span::call_site()), types: default_types() } }
+    fn default() -> Self {
+        Self {
+            generic: Ident::new("T", Span::call_site()),
+            maybe_unsized: None,
+            defaults: Defaults::default(),
+            owned: None,
+            library: Library::default(),
+            bounds: Vec::new(),
+            types: default_types(),
+            explicit: HashMap::new(),
+        }
+    }
 }
 impl Parse for Attributes {
     #[inline]
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let default_types: HashSet<TypeToImpl> = default_types();
         let mut first: bool = true;
         let mut attr = Self::default();
         while !input.is_empty() {
@@ -765,6 +1479,24 @@ impl Parse for Attributes {
                 }
             }
 
+            // Parse the `?Sized`-flag first
+            if input.parse::<Token![?]>().is_ok() {
+                let ident: Ident = input.parse()?;
+                if ident != "Sized" {
+                    return Err(Error::new(ident.span(), "Expected 'Sized'"));
+                }
+                attr.maybe_unsized = Some(true);
+                first = false;
+                continue;
+            }
+
+            // Parse any global bounds
+            if input.parse::<Token![where]>().is_ok() {
+                attr.bounds.extend(parse_where_predicates(input)?);
+                first = false;
+                continue;
+            }
+
             // Parse either 'impl' or 'unimpl'
             let add: bool = input.parse::<Token![impl]>().is_ok();
             if !add {
@@ -778,6 +1510,44 @@ impl Parse for Attributes {
                             attr.generic = input.parse::<Ident>()?;
                             first = false;
                             continue;
+                        } else if sident == "Sized" {
+                            attr.maybe_unsized = Some(false);
+                            first = false;
+                            continue;
+                        } else if sident == "core" {
+                            attr.library = Library::Core;
+                            first = false;
+                            continue;
+                        } else if sident == "alloc" {
+                            attr.library = Library::Alloc;
+                            first = false;
+                            continue;
+                        } else if sident == "defaults" {
+                            // Parse what to do with default implementations
+                            input.parse::<Token![=]>()?;
+                            let defaults: Ident = input.parse()?;
+                            attr.defaults = if defaults == "forward" {
+                                Defaults::Forward
+                            } else if defaults == "inherit" {
+                                Defaults::Inherit
+                            } else {
+                                return Err(Error::new(defaults.span(), "Expected either 'forward' or 'inherit'"));
+                            };
+                            first = false;
+                            continue;
+                        } else if sident == "owned" {
+                            // Parse the strategy for shared pointers
+                            input.parse::<Token![=]>()?;
+                            let strategy: Ident = input.parse()?;
+                            attr.owned = Some(if strategy == "unwrap" {
+                                OwnedStrategy::Unwrap
+                            } else if strategy == "clone" {
+                                OwnedStrategy::Clone
+                            } else {
+                                return Err(Error::new(strategy.span(), "Expected either 'unwrap' or 'clone'"));
+                            });
+                            first = false;
+                            continue;
                         } else if sident != "unimpl" {
                             return Err(Error::new(ident.span(), "Expected either 'impl' or 'unimpl'"));
                         }
@@ -786,37 +1556,125 @@ impl Parse for Attributes {
                 }
             }
 
-            // Parse the optional `mut`
-            let mutable: bool = if add { input.parse::<Token![mut]>().is_ok() } else { false };
-
-            // Then parse any generics
+            // Parse the optional `mut` and any generics (in either order)
+            let mut mutable: bool = if add { input.parse::<Token![mut]>().is_ok() } else { false };
             let generics: Option<Generics> = if add { input.parse().ok() } else { None };
+            mutable |= add && input.parse::<Token![mut]>().is_ok();
 
-            // Then parse either a wildcard OR a type
-            if !add && input.parse::<Token![*]>().is_ok() {
-                if add {
-                    attr.types.extend(default_types.iter().cloned());
-                } else {
-                    attr.types.retain(|t| !default_types.contains(t));
+            // Parse the optional `?Sized`
+            let maybe_unsized: bool = if add && input.parse::<Token![?]>().is_ok() {
+                let ident: Ident = input.parse()?;
+                if ident != "Sized" {
+                    return Err(Error::new(ident.span(), "Expected 'Sized'"));
+                }
+                true
+            } else {
+                false
+            };
+
+            // Then parse either a wildcard, a group OR a type
+            let span: Span = input.span();
+            let (name, groups): (&str, Option<Vec<Group>>) = if input.parse::<Token![*]>().is_ok() {
+                ("*", Some(Group::ALL.into_iter().filter(|group| group.is_default()).collect()))
+            } else {
+                let group: Option<Group> = parse_group(input)?;
+                (group.map(Group::name).unwrap_or_default(), group.map(|group| vec![group]))
+            };
+            if let Some(groups) = groups {
+                if mutable || generics.as_ref().is_some_and(|g| g.lt_token.is_some()) || maybe_unsized {
+                    return Err(Error::new(span, "Cannot give `mut`, generics or `?Sized` for a group of types"));
+                }
+                let before: usize = attr.types.len();
+                for group in &groups {
+                    let types: Vec<TypeToImpl> = group.types().map_err(|err| Error::new(span, err))?;
+                    if add {
+                        attr.types.extend(types);
+                    } else {
+                        attr.explicit.retain(|explicit, _| !types.iter().any(|todo| todo.matches(explicit)));
+                        attr.types.retain(|todo| !types.iter().any(|other| other.matches(&todo.ty)));
+                    }
+                }
+
+                // Throw a fit if removing didn't do anything
+                if !add && attr.types.len() == before {
+                    let closest: Option<Group> = Group::ALL
+                        .into_iter()
+                        .filter(|group| {
+                            group.types().is_ok_and(|types| attr.types.iter().any(|todo| types.iter().any(|other| other.matches(&todo.ty))))
+                        })
+                        .min_by_key(|group| (edit_distance(name, group.name()), group.name()));
+                    return Err(Error::new(span, match closest {
+                        Some(closest) => format!("`unimpl {name}` does not match any type to implement (closest is `{}`)", closest.name()),
+                        None => format!("`unimpl {name}` does not match any type to implement (there are none left)"),
+                    }));
                 }
             } else {
                 // Parse the type first
                 let ty: Type = input.parse()?;
 
-                // Optionally parse the closure
-                let closure: Option<Expr> = if input.parse::<Token![=]>().is_ok() { Some(input.parse()?) } else { None };
+                // Optionally parse the accessors and any bounds
+                let braced: bool = input.peek(Brace);
+                let accessors: Accessors = if braced || input.peek(Token![=]) { input.parse()? } else { Accessors::default() };
+                // Giving an accessor for mutable references implies the type is mutable
+                mutable |= braced && accessors.by_mut.is_some();
+                let bounds: Vec<WherePredicate> =
+                    if add && input.parse::<Token![where]>().is_ok() { parse_where_predicates(input)? } else { Vec::new() };
 
                 // Process the changes
                 if add {
-                    attr.types.insert(TypeToImpl { ty, mutable, generics, closure });
+                    attr.explicit.insert(ty.clone(), ty.span());
+                    attr.types.insert(TypeToImpl {
+                        ty,
+                        mutable,
+                        maybe_unsized,
+                        ownership: Ownership::Borrowed,
+                        generics,
+                        accessors,
+                        bounds,
+                        mut_bounds: Vec::new(),
+                        target: None,
+                    });
                 } else {
-                    attr.types.retain(|todo| todo.ty != ty);
+                    let before: usize = attr.types.len();
+                    attr.explicit.retain(|explicit, _| normalize_type(explicit) != normalize_type(&ty));
+                    attr.types.retain(|todo| !todo.matches(&ty));
+
+                    // Throw a fit if it didn't do anything
+                    if attr.types.len() == before {
+                        let target: String = short_type_name(&ty);
+                        let closest: Option<&TypeToImpl> = attr.types.iter().min_by_key(|todo| {
+                            let candidate: String = short_type_name(&todo.ty);
+                            (edit_distance(&target, &candidate), candidate)
+                        });
+                        return Err(Error::new(ty.span(), match closest {
+                            Some(closest) => format!(
+                                "`unimpl {}` does not match any type to implement (closest is `{}`)",
+                                type_to_string(&ty),
+                                type_to_string(&closest.ty)
+                            ),
+                            None => format!("`unimpl {}` does not match any type to implement (there are none left)", type_to_string(&ty)),
+                        }));
+                    }
                 }
             }
 
             // Note we've done one impl
             first = false;
         }
+
+        // Move the types to the chosen standard library, dropping those that don't exist in it
+        if attr.library != Library::Std {
+            let library: Library = attr.library;
+            attr.types = attr.types.into_iter().filter_map(|mut ty| ty.resolve_library(library).then_some(ty)).collect();
+            attr.explicit = attr
+                .explicit
+                .into_iter()
+                .map(|(mut ty, span)| {
+                    LibraryResolver { library, available: true }.visit_type_mut(&mut ty);
+                    (ty, span)
+                })
+                .collect();
+        }
         Ok(attr)
     }
 }
@@ -824,15 +1682,17 @@ impl Parse for Attributes {
 
 
 /// Specifies that which we need to know about every item in the input trait.
-struct ImplsToDo {
+pub(crate) struct ImplsToDo {
     /// The original definition.
-    def: ItemTrait,
+    pub(crate) def: ItemTrait,
     /// A mask of items in `def` to actually do.
-    item_mask: BitVec,
+    pub(crate) item_mask: BitVec,
     /// A list of attributes for items that we generate (i.e., items with the mask on 1)
     item_attrs: HashMap<usize, ItemAttributes>,
-    /// Whether anything in this trait requires interior mutability of the pointer.
-    requires_mutable: bool,
+    /// A mask of items in `def` that are only available if `Self: Sized`.
+    sized_mask: BitVec,
+    /// The kind of receiver of every method that has one.
+    pub(crate) receivers: HashMap<usize, ReceiverKind>,
 }
 impl Parse for ImplsToDo {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -842,7 +1702,8 @@ impl Parse for ImplsToDo {
         // Go through its items to find the interior mutability status
         let mut item_attrs: HashMap<usize, ItemAttributes> = HashMap::with_capacity(def.items.len());
         let mut item_mask: BitVec = BitVec::with_capacity(def.items.len());
-        let mut requires_mutable: bool = false;
+        let mut sized_mask: BitVec = BitVec::with_capacity(def.items.len());
+        let mut receivers: HashMap<usize, ReceiverKind> = HashMap::new();
         for item in &mut def.items {
             // Get the attributes of this item and whether it would be included based on e.g. not
             // having a default implementation
@@ -850,7 +1711,7 @@ impl Parse for ImplsToDo {
                 TraitItem::Const(c) => {
                     // Assert first that there are no generics (wtf does that even mean on
                     // associated constants)
-                    if c.generics.params.is_empty() {
+                    if !c.generics.params.is_empty() {
                         return Err(Error::new(c.generics.span(), "Associated constants with generics are not supported by `#[pointer_impls]`"));
                     }
 
@@ -858,15 +1719,9 @@ impl Parse for ImplsToDo {
                     &mut c.attrs
                 },
                 TraitItem::Fn(f) => {
-                    // First, we mark if this makes the trait require internal mutability
-                    for arg in &f.sig.inputs {
-                        match arg {
-                            FnArg::Receiver(r) => {
-                                requires_mutable |= r.mutability.is_some();
-                                break;
-                            },
-                            _ => {},
-                        }
+                    // First, we note how this method takes `self` (if at all)
+                    if let Some(FnArg::Receiver(r)) = f.sig.inputs.first() {
+                        receivers.insert(item_mask.len(), ReceiverKind::parse(r)?);
                     }
 
                     // Then return the attributes
@@ -889,12 +1744,125 @@ impl Parse for ImplsToDo {
             };
 
             // Decide whether to push based on the presence of the attribute & boolean
-            item_attrs.insert(item_mask.len(), ItemAttributes::try_from(attrs)?);
+            let attrs = ItemAttributes::try_from(attrs)?;
+            if let (Some((_, value)), false) = (attrs.values.first(), matches!(item, TraitItem::Const(_))) {
+                return Err(Error::new(value.span(), "Only associated constants can be given a value"));
+            }
+            item_attrs.insert(item_mask.len(), attrs);
             item_mask.push(true);
         }
 
+        // Mark which of the methods only exist for sized types
+        for item in &def.items {
+            sized_mask.push(matches!(item, TraitItem::Fn(f) if requires_sized(&f.sig.generics)));
+        }
+
         // OK, done
-        Ok(Self { def, item_mask, item_attrs, requires_mutable })
+        Ok(Self { def, item_mask, item_attrs, sized_mask, receivers })
+    }
+}
+impl ImplsToDo {
+    /// Updates the item mask based on which items with default implementations we forward.
+    ///
+    /// # Arguments
+    /// - `defaults`: What to do with methods with default implementations if the user didn't
+    ///   specify it for that method.
+    ///
+    /// # Errors
+    /// This function errors if the user asked to inherit an item that has no default
+    /// implementation, or if an associated constant cannot be forwarded.
+    pub(crate) fn resolve_defaults(&mut self, defaults: Defaults) -> syn::Result<()> {
+        for (i, item) in self.def.items.iter().enumerate() {
+            let Some(attrs) = self.item_attrs.get(&i) else { continue };
+            let has_default: bool = match item {
+                TraitItem::Const(c) => c.default.is_some(),
+                TraitItem::Fn(f) => f.default.is_some(),
+                TraitItem::Type(ty) => ty.default.is_some(),
+                _ => continue,
+            };
+
+            // Constants of a type mentioning `Self` can't be forwarded, since `Self` is a different
+            // type in the impl than in `T`'s
+            if let TraitItem::Const(c) = item {
+                if attrs.values.is_empty() && mentions_self(&c.ty) {
+                    match (attrs.forward, has_default) {
+                        (Some((true, span)), _) => {
+                            return Err(Error::new(
+                                span,
+                                format!(
+                                    "Cannot forward associated constant '{}' because its type mentions `Self` (give it a value with \
+                                     `#[pointer_impl(value = ...)]` instead)",
+                                    c.ident
+                                ),
+                            ));
+                        },
+                        (_, true) => self.item_mask.set(i, false),
+                        (_, false) => {
+                            return Err(Error::new(
+                                c.ty.span(),
+                                format!(
+                                    "Cannot forward associated constant '{}' because its type mentions `Self`, and it has no default \
+                                     implementation (give it a value with `#[pointer_impl(value = ...)]` instead)",
+                                    c.ident
+                                ),
+                            ));
+                        },
+                    }
+                    continue;
+                }
+            }
+
+            match attrs.forward {
+                Some((true, _)) => {},
+                Some((false, span)) => {
+                    if !has_default {
+                        return Err(Error::new(span, "Cannot inherit an item without a default implementation"));
+                    }
+                    self.item_mask.set(i, false);
+                },
+                // By default, only methods are inherited (if the user wants)
+                None => {
+                    if matches!(item, TraitItem::Fn(_)) && has_default && defaults == Defaults::Inherit {
+                        self.item_mask.set(i, false);
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates the generic arguments with which a forwarded method calls the original one.
+    ///
+    /// # Arguments
+    /// - `i`: The index of the method in the trait.
+    /// - `sig`: The [`Signature`] of the method.
+    ///
+    /// # Returns
+    /// A turbofish with either the generics given by the user (`#[pointer_impl(generics = ...)]`),
+    /// or the method's own type- and const parameters.
+    pub(crate) fn call_generics(&self, i: usize, sig: &Signature) -> TokenStream2 {
+        if let Some(generics) = self.item_attrs.get(&i).and_then(|attrs| attrs.generics.as_ref()) {
+            quote! { :: #generics }
+        } else {
+            let mut generics: Generics = sig.generics.clone();
+            generics.params = generics.params.into_iter().filter(|param| !matches!(param, GenericParam::Lifetime(_))).collect();
+            let (_, ty_gen, _) = generics.split_for_impl();
+            ty_gen.as_turbofish().into_token_stream()
+        }
+    }
+
+    /// Finds the first method that needs `T: Sized` in order to be forwarded.
+    ///
+    /// These are methods with a `where Self: Sized`-bound that have no default implementation
+    /// (which would otherwise be inherited by unsized impls).
+    ///
+    /// # Returns
+    /// The [`TraitItemFn`] requiring it, or [`None`] if `T` can be left unsized.
+    fn requires_sized(&self) -> Option<&TraitItemFn> {
+        self.def.items.iter().enumerate().find_map(|(i, item)| match item {
+            TraitItem::Fn(f) if self.item_mask[i] && self.sized_mask[i] && f.default.is_none() => Some(f),
+            _ => None,
+        })
     }
 }
 
@@ -902,6 +1870,28 @@ impl Parse for ImplsToDo {
 struct ItemAttributes {
     /// The list of generics to push for this item.
     generics: Option<AngleBracketedGenericArguments>,
+    /// Whether to forward (true) or inherit (false) this item, if the user said so.
+    forward:  Option<(bool, Span)>,
+    /// Values to give to associated constants instead of forwarding them, optionally only for
+    /// some of the types.
+    values:   Vec<(Option<Vec<Type>>, Expr)>,
+}
+impl ItemAttributes {
+    /// Finds the value to give to an associated constant for a particular type.
+    ///
+    /// # Arguments
+    /// - `to_impl`: The [`TypeToImpl`] to find the value for.
+    ///
+    /// # Returns
+    /// The user-given value, or [`None`] if the constant should be forwarded. Values given for
+    /// specific types take precedence over values given for all of them.
+    fn value(&self, to_impl: &TypeToImpl) -> Option<&Expr> {
+        self.values
+            .iter()
+            .find(|(types, _)| types.as_ref().is_some_and(|types| types.iter().any(|ty| to_impl.matches(ty))))
+            .or_else(|| self.values.iter().find(|(types, _)| types.is_none()))
+            .map(|(_, value)| value)
+    }
 }
 impl TryFrom<&mut Vec<Attribute>> for ItemAttributes {
     type Error = syn::Error;
@@ -909,8 +1899,9 @@ impl TryFrom<&mut Vec<Attribute>> for ItemAttributes {
     fn try_from(value: &mut Vec<Attribute>) -> Result<Self, Self::Error> {
         // Collect the attributes of interest
         let mut attrs: Vec<TokenStream2> = Vec::with_capacity(value.len());
+        let mut include_impl: Option<Span> = None;
         value.retain_mut(|attr| match &mut attr.meta {
-            // We're interested in `#[pointer_impl(...)]` only (for now)
+            // We're interested in `#[pointer_impl(...)]`...
             Meta::List(l) => {
                 if l.path.is_ident("pointer_impl") {
                     // Match; extract the attribute from it (so it doesn't linger in the re-
@@ -919,31 +1910,56 @@ impl TryFrom<&mut Vec<Attribute>> for ItemAttributes {
                     std::mem::swap(&mut tokens, &mut l.tokens);
                     attrs.push(tokens);
                     false
+                } else {
+                    // Those of `#[tuple_impls]`, `#[collection_impls]` and `#[mock_impls]` are parsed by
+                    // them first, and shouldn't end up in impls
+                    !l.path.is_ident("tuple_impl") && !l.path.is_ident("collection_impl") && !l.path.is_ident("mock_impl")
+                }
+            },
+
+            // ...and `#[include_impl]`
+            Meta::Path(p) => {
+                if p.is_ident("include_impl") {
+                    include_impl = Some(p.span());
+                    false
                 } else {
                     true
                 }
             },
 
-            Meta::Path(_) | Meta::NameValue(_) => true,
+            Meta::NameValue(_) => true,
         });
 
         // Attempt to parse each of those
-        let mut attr = Self { generics: None };
+        let mut attr = Self { generics: None, forward: include_impl.map(|span| (true, span)), values: Vec::new() };
         for tokens in attrs {
             let metas: Punctuated<BetterMeta, Token![,]> = syn::parse2::<BetterMetas>(tokens)?.0;
             for meta in metas {
                 match meta {
                     BetterMeta::NameValue(nv) => {
-                        if nv.path.is_ident("generics") {
+                        if nv.path.is_ident("generics") && nv.args.is_none() {
                             // Parse the value as the required list
                             attr.generics = Some(syn::parse2(nv.value)?);
+                        } else if nv.path.is_ident("value") {
+                            // Parse the types for which it holds, if any, and then the value itself
+                            let types: Option<Vec<Type>> = match nv.args {
+                                Some(args) => Some(Punctuated::<Type, Token![,]>::parse_terminated.parse2(args)?.into_iter().collect()),
+                                None => None,
+                            };
+                            attr.values.push((types, syn::parse2(nv.value)?));
                         } else {
                             return Err(Error::new(nv.path.span(), format!("Unknown pointer_impl attribute {}", nv.path.into_token_stream())));
                         }
                     },
 
                     BetterMeta::Path(p) => {
-                        return Err(Error::new(p.span(), format!("Unknown pointer_impl attribute {}", p.into_token_stream())));
+                        if p.is_ident("forward") {
+                            attr.forward = Some((true, p.span()));
+                        } else if p.is_ident("inherit") {
+                            attr.forward = Some((false, p.span()));
+                        } else {
+                            return Err(Error::new(p.span(), format!("Unknown pointer_impl attribute {}", p.into_token_stream())));
+                        }
                     },
                     BetterMeta::List(l) => {
                         return Err(Error::new(l.path.span(), format!("Unknown pointer_impl attribute {}", l.path.into_token_stream())));
@@ -976,41 +1992,61 @@ enum BetterMeta {
 impl Parse for BetterMeta {
     #[inline]
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if let Ok(nv) = input.parse::<BetterMetaNameValue>() {
-            return Ok(Self::NameValue(nv));
+        // Always start with the path, then decide what follows
+        let path: Path = input.parse()?;
+        if input.peek(Token![=]) {
+            return Ok(Self::NameValue(BetterMetaNameValue { path, args: None, _eq_token: input.parse()?, value: input.parse()? }));
         }
-        if let Ok(l) = input.parse::<MetaList>() {
-            return Ok(Self::List(l));
+        if input.peek(Paren) || input.peek(Bracket) || input.peek(Brace) {
+            let TokenTree::Group(group) = input.parse::<TokenTree>()? else { unreachable!() };
+            if group.delimiter() == Delimiter::Parenthesis && input.peek(Token![=]) {
+                return Ok(Self::NameValue(BetterMetaNameValue {
+                    path,
+                    args: Some(group.stream()),
+                    _eq_token: input.parse()?,
+                    value: input.parse()?,
+                }));
+            }
+            let delimiter: MacroDelimiter = match group.delimiter() {
+                Delimiter::Parenthesis => MacroDelimiter::Paren(Paren(group.delim_span())),
+                Delimiter::Bracket => MacroDelimiter::Bracket(Bracket(group.delim_span())),
+                _ => MacroDelimiter::Brace(Brace(group.delim_span())),
+            };
+            return Ok(Self::List(MetaList { path, delimiter, tokens: group.stream() }));
         }
-        Ok(Self::Path(input.parse()?))
+        Ok(Self::Path(path))
     }
 }
 
 /// Specifies a more lenient [`MetaNameValue`](syn::MetaNameValue).
+///
+/// Also accepts an optional parenthesized list of arguments in between the path and the value
+/// (e.g., `value(Foo, Bar) = 42`).
 struct BetterMetaNameValue {
     path:      Path,
+    args:      Option<TokenStream2>,
     _eq_token: Token![=],
     value:     TokenStream2,
 }
-impl Parse for BetterMetaNameValue {
-    #[inline]
-    fn parse(input: ParseStream) -> syn::Result<Self> { Ok(Self { path: input.parse()?, _eq_token: input.parse()?, value: input.parse()? }) }
-}
 
 
 
 /// Implements the main struct doing the heavy lifting.
-struct Generator {
+pub(crate) struct Generator {
     /// What we parsed from the attribute stream.
-    attrs: Attributes,
+    pub(crate) attrs: Attributes,
     /// What we parsed from the item stream.
-    todo:  ImplsToDo,
+    pub(crate) todo:  ImplsToDo,
+    /// The path to refer to the trait by in the impls, if not by its name.
+    pub(crate) path:  Option<Path>,
 }
-impl ToTokens for Generator {
-    #[inline]
-    fn to_tokens(&self, tokens: &mut TokenStream2) {
-        // Define the `T`-type
-        let t = TypePath {
+impl Generator {
+    /// Returns the `T`-type that the impls forward to.
+    ///
+    /// # Returns
+    /// A [`Type`] that is simply a path to the generic.
+    fn t(&self) -> Type {
+        Type::Path(TypePath {
             qself: None,
             path:  Path {
                 leading_colon: None,
@@ -1020,39 +2056,136 @@ impl ToTokens for Generator {
                     puncts
                 },
             },
-        };
-        let mut infer_resolver = InferResolver { ty: t.clone() };
-        let mut self_resolver = SelfResolver { ident: self.attrs.generic.clone() };
+        })
+    }
 
-        // Resolve the types in the original definition, once for `Self` and once for `T`
+    /// Returns the original definition of the trait, with any inferred types resolved to `T`.
+    ///
+    /// # Returns
+    /// The [`ItemTrait`] to write and to generate the impls for.
+    fn def(&self) -> ItemTrait {
         let mut def = self.todo.def.clone();
-        infer_resolver.visit_item_trait_mut(&mut def);
+        InferResolver { ty: self.t() }.visit_item_trait_mut(&mut def);
+        def
+    }
 
-        // First, write the original definition
-        def.to_tokens(tokens);
+    /// Generates only the impls of the trait, without its definition.
+    ///
+    /// # Arguments
+    /// - `tokens`: The [`TokenStream2`] to write the impls to.
+    pub(crate) fn impls_to_tokens(&self, tokens: &mut TokenStream2) {
+        let t: Type = self.t();
+        let def: ItemTrait = self.def();
+        let mut self_resolver = SelfResolver { ident: self.attrs.generic.clone() };
 
         // Extract some things from the def
-        let name: &Ident = &def.ident;
+        let name: Path = self.path.clone().unwrap_or_else(|| def.ident.clone().into());
         let mut generics: Generics = def.generics.clone();
         self_resolver.visit_generics_mut(&mut generics);
         let (_, trait_ty_gen, trait_where_clause) = generics.split_for_impl();
+        let requires_sized: bool = self.todo.requires_sized().is_some();
 
         // Generate an implementation for each of the given pointer types
-        for to_impl in &self.attrs.types {
-            // Skip this impl if it requires mutability
-            if !to_impl.mutable && self.todo.requires_mutable {
-                continue;
-            }
+        'types: for to_impl in &self.attrs.types {
+            // Decide whether this impl can be given for unsized types
+            let forced_unsized: bool = to_impl.maybe_unsized && self.attrs.maybe_unsized == Some(true);
+            let mut maybe_unsized: bool = to_impl.maybe_unsized && self.attrs.maybe_unsized != Some(false) && !requires_sized;
+
+            // Find the type that implements the trait. This is usually `T`, but types like `Pin<P>`
+            // rely on a projection instead (i.e., `P::Target`), in which case `T` doesn't exist.
+            let t: Type = to_impl.target.clone().unwrap_or_else(|| t.clone());
+            let mut infer_resolver = InferResolver { ty: t.clone() };
+            let mut generic_resolver = GenericResolver { ident: self.attrs.generic.clone(), ty: t.clone() };
 
             // Resolve the type's inferred to concrete ones
             let mut ty: Type = to_impl.ty.clone();
             infer_resolver.visit_type_mut(&mut ty);
 
+            // Collect the bounds given by the user, and the trait bound on any projection
+            let mut bounds: Vec<WherePredicate> = Vec::with_capacity(1 + self.attrs.bounds.len() + to_impl.bounds.len());
+            if to_impl.target.is_some() {
+                bounds.push(parse_quote! { #t: #name #trait_ty_gen });
+            }
+            for bound in self.attrs.bounds.iter().chain(to_impl.bounds.iter()) {
+                let mut bound: WherePredicate = bound.clone();
+                infer_resolver.visit_where_predicate_mut(&mut bound);
+                if to_impl.target.is_some() {
+                    generic_resolver.visit_where_predicate_mut(&mut bound);
+                }
+                if !bounds.contains(&bound) {
+                    bounds.push(bound);
+                }
+            }
+
+            // Find how to pass `self` to every method. We do this in two passes: first for methods
+            // always forwarded, and only then those that are forwarded if `T` is sized.
+            let mut accesses: HashMap<usize, Access> = HashMap::with_capacity(self.todo.receivers.len());
+            for sized_only in [false, true] {
+                for (i, item) in def.items.iter().enumerate() {
+                    let Some(receiver) = self.todo.receivers.get(&i) else { continue };
+                    let TraitItem::Fn(f) = item else { continue };
+                    if !self.todo.item_mask[i] || self.todo.sized_mask[i] != sized_only || (sized_only && maybe_unsized) {
+                        continue;
+                    }
+
+                    // See if the type supports it
+                    let reason: String = match to_impl.access(*receiver, self.attrs.owned, &t, &ty) {
+                        Ok(access) if access.sized && forced_unsized => format!("it requires `{}: Sized`, but `?Sized` was given", self.attrs.generic),
+                        Ok(access) => {
+                            maybe_unsized &= !access.sized;
+                            for bound in &access.bounds {
+                                if !bounds.contains(bound) {
+                                    bounds.push(bound.clone());
+                                }
+                            }
+                            accesses.insert(i, access);
+                            continue;
+                        },
+                        Err(reason) => reason,
+                    };
+
+                    // Methods with a default implementation can simply be inherited; otherwise, we
+                    // drop the type (which is an error if the user asked for it)
+                    if f.default.is_none() {
+                        if let Some(span) = self.attrs.explicit.get(&to_impl.ty) {
+                            tokens.extend(
+                                Error::new(
+                                    *span,
+                                    format!(
+                                        "Cannot implement `{}` for `{}` because method '{}' takes `{}`, but {}",
+                                        def.ident,
+                                        type_to_string(&to_impl.ty),
+                                        f.sig.ident,
+                                        receiver,
+                                        reason
+                                    ),
+                                )
+                                .into_compile_error(),
+                            );
+                        }
+                        continue 'types;
+                    }
+                }
+            }
+
             // Inject the necessary types
             let mut altered_generics = def.generics.clone();
-            inject_additional_types(&self.attrs.generic, &def, &to_impl.generics, &mut altered_generics);
+            let generic: Option<&Ident> = if to_impl.target.is_none() { Some(&self.attrs.generic) } else { None };
+            inject_additional_types(generic, &def, &to_impl.generics, maybe_unsized, &mut altered_generics);
             let (trait_impl_gen, _, _) = altered_generics.split_for_impl();
 
+            // Add any bounds given by the user or required by the accesses
+            let mut where_clause: Option<WhereClause> = trait_where_clause.cloned();
+            if let (Some(where_clause), Some(_)) = (&mut where_clause, &to_impl.target) {
+                generic_resolver.visit_where_clause_mut(where_clause);
+            }
+            if !bounds.is_empty() {
+                where_clause
+                    .get_or_insert_with(|| WhereClause { where_token: Default::default(), predicates: Punctuated::new() })
+                    .predicates
+                    .extend(bounds);
+            }
+
             // Build the items of the impls
             let mut items: Vec<TokenStream2> = Vec::with_capacity(self.todo.item_mask.count_ones());
             for (i, item) in def.items.iter().enumerate() {
@@ -1060,6 +2193,14 @@ impl ToTokens for Generator {
                 if !self.todo.item_mask[i] {
                     continue;
                 }
+                // Methods only existing for sized types are inherited if we don't know that
+                if maybe_unsized && self.todo.sized_mask[i] {
+                    continue;
+                }
+                // Methods that this type can't pass `self` to are inherited
+                if self.todo.receivers.contains_key(&i) && !accesses.contains_key(&i) {
+                    continue;
+                }
 
                 // Generate the impl
                 match item {
@@ -1082,7 +2223,11 @@ impl ToTokens for Generator {
                         } else {
                             <Token![=]>::default().to_tokens(&mut tokens);
                         }
-                        tokens.extend(quote! {<#t as #name #trait_ty_gen>::#ident #semi_token });
+                        if let Some(value) = self.todo.item_attrs.get(&i).unwrap().value(to_impl) {
+                            tokens.extend(quote! { #value #semi_token });
+                        } else {
+                            tokens.extend(quote! { <#t as #name #trait_ty_gen>::#ident #semi_token });
+                        }
 
                         // Keep it!
                         items.push(tokens);
@@ -1094,18 +2239,21 @@ impl ToTokens for Generator {
                         let ident: &Ident = &sig.ident;
 
                         // Collect the parameters (which are patterns, of course :#)
-                        let mut this: Option<Ident> = None;
-                        let passing_args: Punctuated<Pat, Token![,]> = sig
-                            .inputs
-                            .iter()
-                            .filter_map(|a| match a {
-                                FnArg::Receiver(r) => {
-                                    this = Some(Ident::new("self", r.span()));
-                                    None
-                                },
-                                FnArg::Typed(t) => Some((*t.pat).clone()),
-                            })
-                            .collect();
+                        let passing_args: Punctuated<Pat, Token![,]> = passing_args(sig);
+
+                        // We don't need mutable `self` if we move it
+                        let mut sig = sig.clone();
+                        strip_owned_mut(&mut sig);
+
+                        // Async methods are desugared such that we can return `T`'s future as-is
+                        // (which keeps its auto traits, like `Send`)
+                        if sig.asyncness.take().is_some() {
+                            let output: Type = match &sig.output {
+                                ReturnType::Default => parse_quote! { () },
+                                ReturnType::Type(_, ty) => (**ty).clone(),
+                            };
+                            sig.output = parse_quote! { -> impl ::core::future::Future<Output = #output> };
+                        }
 
                         // Generate the associated method's impl as:
                         // ```
@@ -1113,69 +2261,36 @@ impl ToTokens for Generator {
                         // fn bar(&self, baz: Quz) -> Qux { <T as Cuz>::bar(self, baz) }
                         // ```
                         let mut tokens = quote! { #(#attrs)* #sig };
-                        if let Some(block) = default {
-                            block.brace_token.surround(&mut tokens, |tokens| {
-                                // Either generate the default types, or the custom one
-                                if let Some(generics) = &self.todo.item_attrs.get(&i).unwrap().generics {
-                                    tokens.extend(quote! { <#t as #name #trait_ty_gen>::#ident :: #generics });
-                                } else {
-                                    let mut generics: Generics = sig.generics.clone();
-                                    generics.params =
-                                        generics.params.into_iter().filter(|param| !matches!(param, GenericParam::Lifetime(_))).collect();
-                                    let (_, ty_gen, _) = generics.split_for_impl();
-                                    let ty_gen = ty_gen.as_turbofish();
-                                    tokens.extend(quote! { <#t as #name #trait_ty_gen>::#ident #ty_gen });
-                                }
-
-                                // Write the contents of the parenthesis
-                                sig.paren_token.surround(tokens, |tokens| {
-                                    if let Some(this) = &this {
-                                        if let Some(closure) = &to_impl.closure {
-                                            tokens.extend(quote! { #closure })
-                                        } else {
-                                            tokens.extend(quote! { #this })
-                                        }
-                                    }
-                                    if !passing_args.is_empty() {
-                                        if this.is_some() {
-                                            tokens.extend(quote! {,});
-                                        }
-                                        passing_args.to_tokens(tokens);
-                                    }
-                                });
-                            });
-                        } else {
-                            Brace::default().surround(&mut tokens, |tokens| {
-                                // Either generate the default types, or the custom one
-                                if let Some(generics) = &self.todo.item_attrs.get(&i).unwrap().generics {
-                                    tokens.extend(quote! { <#t as #name #trait_ty_gen>::#ident :: #generics });
-                                } else {
-                                    let mut generics: Generics = sig.generics.clone();
-                                    generics.params =
-                                        generics.params.into_iter().filter(|param| !matches!(param, GenericParam::Lifetime(_))).collect();
-                                    let (_, ty_gen, _) = generics.split_for_impl();
-                                    let ty_gen = ty_gen.as_turbofish();
-                                    tokens.extend(quote! { <#t as #name #trait_ty_gen>::#ident #ty_gen });
+                        let brace_token: Brace = default.as_ref().map(|b| b.brace_token).unwrap_or_default();
+
+                        // Either generate the default types, or the custom one
+                        let call_generics: TokenStream2 = self.todo.call_generics(i, &sig);
+                        let mut call = quote! { <#t as #name #trait_ty_gen>::#ident #call_generics };
+
+                        // Unsafe methods can only be called in an unsafe block, but the (possibly
+                        // user-given) access to the receiver shouldn't be in there
+                        let (lets, receiver): (TokenStream2, Option<TokenStream2>) = match accesses.get(&i) {
+                            Some(Access { expr, .. }) if sig.unsafety.is_some() => {
+                                let this = Ident::new("this", Span::mixed_site());
+                                (quote! { let #this = #expr; }, Some(this.into_token_stream()))
+                            },
+                            Some(Access { expr, .. }) => (TokenStream2::new(), Some(expr.clone())),
+                            None => (TokenStream2::new(), None),
+                        };
+
+                        // Write the contents of the parenthesis
+                        sig.paren_token.surround(&mut call, |tokens| {
+                            if let Some(receiver) = &receiver {
+                                receiver.to_tokens(tokens);
+                            }
+                            if !passing_args.is_empty() {
+                                if receiver.is_some() {
+                                    tokens.extend(quote! {,});
                                 }
-
-                                // Write the contents of the parenthesis
-                                sig.paren_token.surround(tokens, |tokens| {
-                                    if let Some(this) = &this {
-                                        if let Some(closure) = &to_impl.closure {
-                                            tokens.extend(quote! { #closure })
-                                        } else {
-                                            tokens.extend(quote! { #this })
-                                        }
-                                    }
-                                    if !passing_args.is_empty() {
-                                        if this.is_some() {
-                                            tokens.extend(quote! {,});
-                                        }
-                                        passing_args.to_tokens(tokens);
-                                    }
-                                });
-                            });
-                        }
+                                passing_args.to_tokens(tokens);
+                            }
+                        });
+                        body_to_tokens(&sig, brace_token, lets, call, &mut tokens);
 
                         // Keep it!
                         items.push(tokens);
@@ -1211,10 +2326,19 @@ impl ToTokens for Generator {
             }
 
             // Now build the overall impl
-            tokens.extend(quote! { impl #trait_impl_gen #name #trait_ty_gen for #ty #trait_where_clause { #(#items)* } })
+            let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+            tokens.extend(quote! { #unsafety impl #trait_impl_gen #name #trait_ty_gen for #ty #where_clause { #(#items)* } })
         }
     }
 }
+impl ToTokens for Generator {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        // First, write the original definition, and then the impls for it
+        self.def().to_tokens(tokens);
+        self.impls_to_tokens(tokens);
+    }
+}
 
 
 
@@ -1234,8 +2358,33 @@ impl ToTokens for Generator {
 /// This function may error if anything about the input was incompatible with this macro.
 pub fn pointer_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
     // Parse the two streams into the Generator, which will do the necessary generation
-    let generator: Generator = Generator { attrs: syn::parse2(attr)?, todo: syn::parse2(item)? };
+    let raw: ItemTrait = syn::parse2(item.clone())?;
+    let mut generator: Generator = Generator { attrs: syn::parse2(attr)?, todo: syn::parse2(item)?, path: None };
+
+    // Decide which items with default implementations to forward
+    generator.todo.resolve_defaults(generator.attrs.defaults)?;
+
+    // Assert that the trait supports unsized `T`s if the user asked for it
+    if generator.attrs.maybe_unsized == Some(true) {
+        if let Some(f) = generator.todo.requires_sized() {
+            return Err(Error::new(
+                f.sig.ident.span(),
+                format!(
+                    "Method '{}' requires `Self: Sized` and has no default implementation, so it cannot be forwarded to an unsized `{}` (remove \
+                     `?Sized` from `#[pointer_impls(...)]` to only implement for sized types)",
+                    f.sig.ident, generator.attrs.generic
+                ),
+            ));
+        }
+    }
 
-    // Aaaaaand generate it
-    Ok(generator.to_token_stream())
+    // Aaaaaand generate it. If any of our other macros still has to run, it needs the item
+    // attributes, so we write the definition as-is.
+    if has_pending_impls(&raw) {
+        let mut tokens: TokenStream2 = raw.into_token_stream();
+        generator.impls_to_tokens(&mut tokens);
+        Ok(tokens)
+    } else {
+        Ok(generator.to_token_stream())
+    }
 }
@@ -0,0 +1,457 @@
+//  TUPLE IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 09:07:40
+//  Last edited:
+//    17 Oct 2026, 09:18:09
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines an attribute macro for automatically implementing traits for
+//!   tuples of implementors.
+//
+
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Brace;
+use syn::{
+    Attribute, Error, Expr, FnArg, GenericParam, Ident, Index, ItemTrait, LitInt, Pat, ReturnType, Token, TraitItem, TraitItemConst, TraitItemFn,
+    TraitItemType, Type, WhereClause, WherePredicate, parse_quote,
+};
+
+use crate::pointer_impls::{
+    ImplsToDo, ReceiverKind, body_to_tokens, free_ident, generic_args, has_pending_impls, mentions_self, passing_args, returns_self, returns_unit,
+    strip_owned_mut,
+};
+
+
+/***** CONSTANTS *****/
+/// The largest tuple to implement for if the user doesn't say.
+const DEFAULT_MAX: usize = 12;
+
+
+
+
+
+/***** PARSING *****/
+/// Specifies the attributes we're parsing from the `#[tuple_impls(...)]`-attribute.
+struct TupleAttributes {
+    /// The largest tuple to implement for.
+    max: usize,
+}
+impl Parse for TupleAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut max: usize = DEFAULT_MAX;
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "max" {
+                return Err(Error::new(key.span(), format!("Unknown tuple_impls attribute '{key}'")));
+            }
+            input.parse::<Token![=]>()?;
+            let lit: LitInt = input.parse()?;
+            max = lit.base10_parse()?;
+            if max == 0 {
+                return Err(Error::new(lit.span(), "There are no tuples to implement for if `max = 0`"));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Self { max })
+    }
+}
+
+/// Describes how to combine what the elements of the tuple return.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Reduce {
+    /// `a && b && ...`
+    And,
+    /// `a || b || ...`
+    Or,
+    /// `a + b + ...`
+    Sum,
+    /// Return what the first element returns (after calling the others).
+    First,
+    /// Concatenate the returned collections.
+    Collect,
+}
+
+/// Specifies the attributes users can give on trait items (e.g., `#[tuple_impl(...)]`).
+#[derive(Default)]
+pub(crate) struct ReduceAttributes {
+    /// How to combine the results of the elements.
+    pub(crate) reduce: Option<(Reduce, Span)>,
+    /// The value of an associated constant.
+    pub(crate) value:  Option<Expr>,
+    /// The value of an associated type.
+    pub(crate) ty:     Option<Type>,
+}
+impl ReduceAttributes {
+    /// Parses the attributes with the given name from the items of a trait, removing them.
+    ///
+    /// # Arguments
+    /// - `def`: The [`ItemTrait`] to parse from.
+    /// - `name`: The name of the attribute to parse (e.g., `tuple_impl`).
+    ///
+    /// # Returns
+    /// A map of the index of every item to what was found on it.
+    ///
+    /// # Errors
+    /// This function errors if any of the attributes are invalid.
+    pub(crate) fn extract_items(def: &mut ItemTrait, name: &str) -> syn::Result<HashMap<usize, Self>> {
+        let mut res: HashMap<usize, Self> = HashMap::new();
+        for (i, item) in def.items.iter_mut().enumerate() {
+            let attrs: &mut Vec<Attribute> = match item {
+                TraitItem::Const(c) => &mut c.attrs,
+                TraitItem::Fn(f) => &mut f.attrs,
+                TraitItem::Type(ty) => &mut ty.attrs,
+                _ => continue,
+            };
+            res.insert(i, Self::extract(attrs, name)?);
+        }
+        Ok(res)
+    }
+
+    /// Parses the attributes with the given name from a list of attributes, removing them.
+    ///
+    /// # Arguments
+    /// - `attrs`: The list of [`Attribute`]s to parse from.
+    /// - `name`: The name of the attribute to parse (e.g., `tuple_impl`).
+    ///
+    /// # Returns
+    /// A new ReduceAttributes with what was found.
+    ///
+    /// # Errors
+    /// This function errors if any of the attributes are invalid.
+    fn extract(attrs: &mut Vec<Attribute>, name: &str) -> syn::Result<Self> {
+        let mut res = Self::default();
+        let mut err: Option<Error> = None;
+        attrs.retain(|attr| {
+            if !attr.path().is_ident(name) {
+                return true;
+            }
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("reduce") {
+                    let reduce: Ident = meta.value()?.parse()?;
+                    let kind: Reduce = if reduce == "and" {
+                        Reduce::And
+                    } else if reduce == "or" {
+                        Reduce::Or
+                    } else if reduce == "sum" {
+                        Reduce::Sum
+                    } else if reduce == "first" {
+                        Reduce::First
+                    } else if reduce == "collect" {
+                        Reduce::Collect
+                    } else {
+                        return Err(Error::new(reduce.span(), "Expected either 'and', 'or', 'sum', 'first' or 'collect'"));
+                    };
+                    res.reduce = Some((kind, reduce.span()));
+                    Ok(())
+                } else if meta.path.is_ident("value") {
+                    res.value = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("type") {
+                    res.ty = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("Unknown {name} attribute {}", meta.path.to_token_stream())))
+                }
+            });
+            if let Err(e) = parsed {
+                err = Some(e);
+            }
+            false
+        });
+        match err {
+            Some(err) => Err(err),
+            None => Ok(res),
+        }
+    }
+}
+
+
+
+
+
+/***** GENERATION *****/
+/// Implements the main struct doing the heavy lifting.
+struct Generator {
+    /// What we parsed from the attribute stream.
+    attrs: TupleAttributes,
+    /// What we parsed from the item stream.
+    todo: ImplsToDo,
+    /// What we parsed from the `#[tuple_impl(...)]`-attributes on its items.
+    item_attrs: HashMap<usize, ReduceAttributes>,
+}
+impl Generator {
+    /// Checks whether every item of the trait can be implemented, and inherits those that can't if
+    /// possible.
+    ///
+    /// # Errors
+    /// This function errors if an item can't be implemented and has no default.
+    fn check(&mut self) -> syn::Result<()> {
+        let name: &Ident = &self.todo.def.ident;
+        for (i, item) in self.todo.def.items.iter().enumerate() {
+            if !self.todo.item_mask[i] {
+                continue;
+            }
+            let attrs: Option<&ReduceAttributes> = self.item_attrs.get(&i);
+            let reduce: Option<(Reduce, Span)> = attrs.and_then(|attrs| attrs.reduce);
+            match item {
+                TraitItem::Type(ty) => {
+                    if let Some((_, span)) = reduce {
+                        return Err(Error::new(span, "Associated types cannot be reduced (give them with `#[tuple_impl(type = ...)]` instead)"));
+                    }
+                    if !ty.generics.params.is_empty() {
+                        return Err(Error::new(ty.ident.span(), format!("Generic associated type '{}' is not supported by `#[tuple_impls]`", ty.ident)));
+                    }
+                    if attrs.and_then(|attrs| attrs.ty.as_ref()).is_none() {
+                        return Err(Error::new(
+                            ty.ident.span(),
+                            format!(
+                                "Cannot implement associated type '{}' for tuples because it might differ per element (give it with `#[tuple_impl(type \
+                                 = ...)]`)",
+                                ty.ident
+                            ),
+                        ));
+                    }
+                },
+
+                TraitItem::Const(c) => {
+                    let reason: Option<String> = match (reduce, attrs.and_then(|attrs| attrs.value.as_ref())) {
+                        (Some((Reduce::Collect, span)), _) => return Err(Error::new(span, "Associated constants cannot be collected")),
+                        (Some((_, span)), Some(_)) => return Err(Error::new(span, "Cannot give both a value and a reduction")),
+                        (Some(_), None) | (None, Some(_)) => None,
+                        (None, None) => Some("it might differ per element (give it with `#[tuple_impl(value = ...)]`, or combine it with \
+                                              `#[tuple_impl(reduce = ...)]`)"
+                            .into()),
+                    };
+                    if let Some(reason) = reason {
+                        if c.default.is_some() {
+                            self.todo.item_mask.set(i, false);
+                            continue;
+                        }
+                        return Err(Error::new(c.ident.span(), format!("Cannot implement associated constant '{}' for tuples because {reason}", c.ident)));
+                    }
+                },
+
+                TraitItem::Fn(f) => {
+                    let sig = &f.sig;
+                    let mentions_self: bool = sig.inputs.iter().any(|arg| matches!(arg, FnArg::Typed(t) if mentions_self(&t.ty)))
+                        || (!returns_self(&sig.output) && matches!(&sig.output, ReturnType::Type(_, ty) if mentions_self(ty)));
+                    let reason: Option<String> = match (self.todo.receivers.get(&i), reduce) {
+                        (Some(ReceiverKind::Box | ReceiverKind::Rc | ReceiverKind::Arc | ReceiverKind::PinRef | ReceiverKind::PinMut), _) => {
+                            Some(format!("it takes `{}`, which can't be split into the tuple's elements", self.todo.receivers[&i]))
+                        },
+                        _ if mentions_self => Some("its signature mentions `Self` other than as return type".into()),
+                        (_, Some((_, span))) if returns_self(&sig.output) => {
+                            return Err(Error::new(span, format!("Method '{}' returns `Self`, which is always a tuple of what the elements return", sig.ident)));
+                        },
+                        (_, Some((_, span))) if returns_unit(&sig.output) => {
+                            return Err(Error::new(span, format!("Method '{}' returns nothing, so there is nothing to reduce", sig.ident)));
+                        },
+                        (_, None) if !returns_unit(&sig.output) && !returns_self(&sig.output) => {
+                            Some("it returns a value (give how to combine them with `#[tuple_impl(reduce = ...)]`)".into())
+                        },
+                        _ => None,
+                    };
+                    if let Some(reason) = reason {
+                        if f.default.is_some() {
+                            self.todo.item_mask.set(i, false);
+                            continue;
+                        }
+                        return Err(Error::new(sig.ident.span(), format!("Cannot implement method '{}' of trait '{name}' for tuples because {reason}", sig.ident)));
+                    }
+                },
+
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    /// Combines the results of every element of the tuple.
+    ///
+    /// # Arguments
+    /// - `calls`: The expressions that produce the result of every element.
+    /// - `reduce`: How to combine them, if at all.
+    /// - `returns_self`: Whether the result is `Self`, in which case they are combined to a tuple.
+    ///
+    /// # Returns
+    /// An expression that produces the combined result.
+    fn reduce(calls: &[TokenStream2], reduce: Option<Reduce>, returns_self: bool) -> TokenStream2 {
+        match reduce {
+            _ if returns_self => quote! { (#(#calls,)*) },
+            None => quote! { #(#calls;)* },
+            Some(Reduce::And) => quote! { #(#calls)&&* },
+            Some(Reduce::Or) => quote! { #(#calls)||* },
+            Some(Reduce::Sum) => quote! { #(#calls)+* },
+            Some(Reduce::First) => {
+                let first = Ident::new("first", Span::mixed_site());
+                let (head, tail) = calls.split_first().unwrap();
+                quote! { let #first = #head; #(let _ = #tail;)* #first }
+            },
+            Some(Reduce::Collect) => quote! {
+                ::core::iter::FromIterator::from_iter(::core::iter::Iterator::flatten(::core::iter::IntoIterator::into_iter([#(#calls),*])))
+            },
+        }
+    }
+
+    /// Generates the impl for one tuple.
+    ///
+    /// # Arguments
+    /// - `arity`: The number of elements in the tuple.
+    /// - `tokens`: The [`TokenStream2`] to write the impl to.
+    fn impl_to_tokens(&self, arity: usize, tokens: &mut TokenStream2) {
+        let def: &ItemTrait = &self.todo.def;
+        let name: &Ident = &def.ident;
+        let (_, trait_ty_gen, _) = def.generics.split_for_impl();
+        let trait_args: Vec<TokenStream2> = generic_args(&def.generics);
+        let elem: Ident = free_ident(def, "T");
+        let elems: Vec<Ident> = (0..arity).map(|i| format_ident!("{elem}{i}")).collect();
+
+        // Every element has to implement the trait with the associated types the user gave
+        let bindings: Vec<TokenStream2> = def
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| match item {
+                TraitItem::Type(ty) if self.todo.item_mask[i] => {
+                    let ident: &Ident = &ty.ident;
+                    let value: &Type = self.item_attrs.get(&i)?.ty.as_ref()?;
+                    Some(quote! { #ident = #value })
+                },
+                _ => None,
+            })
+            .collect();
+        let args: Vec<&TokenStream2> = trait_args.iter().chain(bindings.iter()).collect();
+        let mut generics = def.generics.clone();
+        generics.params.extend(elems.iter().map(|elem| -> GenericParam { parse_quote! { #elem } }));
+        let (impl_gen, _, _) = generics.split_for_impl();
+        let mut where_clause: WhereClause = def.generics.where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+        where_clause.predicates.extend(elems.iter().map(|elem| -> WherePredicate {
+            if args.is_empty() { parse_quote! { #elem: #name } } else { parse_quote! { #elem: #name<#(#args),*> } }
+        }));
+
+        // Then generate the items
+        let this = Ident::new("self", Span::call_site());
+        let mut items: Vec<TokenStream2> = Vec::with_capacity(self.todo.item_mask.count_ones());
+        for (i, item) in def.items.iter().enumerate() {
+            if !self.todo.item_mask[i] {
+                continue;
+            }
+            let attrs: Option<&ReduceAttributes> = self.item_attrs.get(&i);
+            let reduce: Option<Reduce> = attrs.and_then(|attrs| attrs.reduce.map(|(reduce, _)| reduce));
+            match item {
+                TraitItem::Type(TraitItemType { attrs: ty_attrs, type_token, ident, semi_token, .. }) => {
+                    let value: Option<&Type> = attrs.and_then(|attrs| attrs.ty.as_ref());
+                    items.push(quote! { #(#ty_attrs)* #type_token #ident = #value #semi_token });
+                },
+
+                TraitItem::Const(TraitItemConst { attrs: c_attrs, const_token, ident, colon_token, ty, semi_token, .. }) => {
+                    let value: TokenStream2 = match attrs.and_then(|attrs| attrs.value.as_ref()) {
+                        Some(value) => value.to_token_stream(),
+                        None => {
+                            let calls: Vec<TokenStream2> = elems.iter().map(|elem| quote! { <#elem as #name #trait_ty_gen>::#ident }).collect();
+                            match reduce {
+                                Some(Reduce::First) => calls[0].clone(),
+                                reduce => Self::reduce(&calls, reduce, false),
+                            }
+                        },
+                    };
+                    items.push(quote! { #(#c_attrs)* #const_token #ident #colon_token #ty = #value #semi_token });
+                },
+
+                TraitItem::Fn(TraitItemFn { attrs: f_attrs, sig, default, .. }) => {
+                    let ident: &Ident = &sig.ident;
+
+                    // We don't need mutable `self` if we move it
+                    let mut sig = sig.clone();
+                    strip_owned_mut(&mut sig);
+
+                    // Owned arguments are cloned for every element but the last
+                    let passing_args: Punctuated<Pat, Token![,]> = passing_args(&sig);
+                    let by_ref: Vec<bool> =
+                        sig.inputs.iter().filter_map(|arg| if let FnArg::Typed(t) = arg { Some(matches!(&*t.ty, Type::Reference(_))) } else { None }).collect();
+                    let call_generics: TokenStream2 = self.todo.call_generics(i, &sig);
+                    let asyncness: Option<TokenStream2> = sig.asyncness.map(|_| quote! { .await });
+                    let calls: Vec<TokenStream2> = elems
+                        .iter()
+                        .enumerate()
+                        .map(|(e, elem)| {
+                            let index = Index::from(e);
+                            let mut args: Vec<TokenStream2> = match self.todo.receivers.get(&i) {
+                                Some(ReceiverKind::Ref) => vec![quote! { &#this.#index }],
+                                Some(ReceiverKind::Mut) => vec![quote! { &mut #this.#index }],
+                                Some(_) => vec![quote! { #this.#index }],
+                                None => Vec::new(),
+                            };
+                            args.extend(passing_args.iter().zip(&by_ref).map(|(arg, by_ref)| {
+                                if *by_ref || e + 1 == arity { arg.to_token_stream() } else { quote! { ::core::clone::Clone::clone(&#arg) } }
+                            }));
+                            quote! { <#elem as #name #trait_ty_gen>::#ident #call_generics (#(#args),*) #asyncness }
+                        })
+                        .collect();
+                    let body: TokenStream2 = Self::reduce(&calls, reduce, returns_self(&sig.output));
+
+                    // Unsafe methods can only be called in an unsafe block
+                    let brace_token: Brace = default.as_ref().map(|b| b.brace_token).unwrap_or_default();
+                    let mut tokens = quote! { #(#f_attrs)* #sig };
+                    body_to_tokens(&sig, brace_token, TokenStream2::new(), body, &mut tokens);
+                    items.push(tokens);
+                },
+
+                _ => {},
+            }
+        }
+
+        // Now build the overall impl
+        let unsafety: &Option<Token![unsafe]> = &def.unsafety;
+        tokens.extend(quote! { #unsafety impl #impl_gen #name #trait_ty_gen for (#(#elems,)*) #where_clause { #(#items)* } });
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Actual implementation of the `tuple_impls`-macro.
+///
+/// # Arguments
+/// - `attr`: The stream that is given with the attribute.
+/// - `item`: The item that the attribute spans.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the original item + implementations for tuples.
+///
+/// # Errors
+/// This function may error if anything about the input was incompatible with this macro.
+pub fn tuple_impls(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    let attrs: TupleAttributes = syn::parse2(attr)?;
+
+    // Take our own attributes off of the items first, and then parse the rest like usual
+    let mut def: ItemTrait = syn::parse2(item)?;
+    let item_attrs: HashMap<usize, ReduceAttributes> = ReduceAttributes::extract_items(&mut def, "tuple_impl")?;
+    let mut todo: ImplsToDo = syn::parse2(def.to_token_stream())?;
+    todo.resolve_defaults(Default::default())?;
+
+    // Write the definition itself. If any of our other macros still has to run, it needs the
+    // `#[pointer_impl(...)]`-attributes.
+    let mut tokens: TokenStream2 = if has_pending_impls(&def) { def.to_token_stream() } else { todo.def.to_token_stream() };
+
+    // Then generate the impls for every tuple
+    let mut generator = Generator { attrs, todo, item_attrs };
+    generator.check()?;
+    for arity in 1..=generator.attrs.max {
+        generator.impl_to_tokens(arity, &mut tokens);
+    }
+    Ok(tokens)
+}